@@ -0,0 +1,55 @@
+//! Módulo responsável pela lista de bloqueio de cada leitor
+//!
+//! Um endereço bloqueado não pode curtir nem comentar as reflexões de quem
+//! o bloqueou; as tentativas falham com `Erro::UsuarioBloqueado`. Retirar
+//! uma curtida feita antes do bloqueio continua permitido.
+
+use soroban_sdk::{panic_with_error, Env, Address, Vec};
+use crate::{ChaveSocial, Erro, MAX_BLOQUEADOS};
+
+pub fn bloquear(env: Env, usuario: Address, bloqueado: Address) {
+    usuario.require_auth();
+
+    if usuario == bloqueado {
+        panic!("Não é possível bloquear a si mesmo");
+    }
+
+    let key = ChaveSocial::Bloqueados(usuario);
+    let mut bloqueados: Vec<Address> = env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(&env));
+
+    if bloqueados.contains(&bloqueado) {
+        panic!("Endereço já bloqueado");
+    }
+
+    if bloqueados.len() >= MAX_BLOQUEADOS {
+        panic!("Limite de endereços bloqueados atingido");
+    }
+
+    bloqueados.push_back(bloqueado);
+    env.storage().persistent().set(&key, &bloqueados);
+}
+
+pub fn desbloquear(env: Env, usuario: Address, bloqueado: Address) {
+    usuario.require_auth();
+
+    let key = ChaveSocial::Bloqueados(usuario);
+    let mut bloqueados: Vec<Address> = env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(&env));
+
+    let indice = bloqueados.first_index_of(&bloqueado).expect("Endereço não está bloqueado");
+    bloqueados.remove(indice);
+    env.storage().persistent().set(&key, &bloqueados);
+}
+
+pub fn listar_bloqueados(env: Env, usuario: Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&ChaveSocial::Bloqueados(usuario))
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
+/// Falha com `Erro::UsuarioBloqueado` se o autor bloqueou quem tenta interagir
+pub(crate) fn exigir_nao_bloqueado(env: &Env, autor: &Address, usuario: &Address) {
+    if listar_bloqueados(env.clone(), autor.clone()).contains(usuario) {
+        panic_with_error!(env, Erro::UsuarioBloqueado);
+    }
+}
@@ -0,0 +1,152 @@
+//! Módulo responsável pelos emblemas de conclusão
+//!
+//! Cada recompensa de livro reivindicada cunha um emblema intransferível
+//! (soulbound) com dono, livro, data de conclusão e número de série.
+//! O emblema substitui a antiga marca booleana: `RecompensaRecebida`
+//! passa a guardar o número de série do emblema do leitor para o livro.
+
+use soroban_sdk::{contractevent, Env, Address, String, Vec};
+use crate::{DataKey, Emblema, MAX_URI_CHARS};
+
+const MARCADOR_ID: &[u8] = b"{id}";
+
+#[contractevent]
+struct EmblemaCunhado {
+    dono: Address,
+    livro_id: u32,
+    serial: u32,
+}
+
+/// Cunha o emblema de conclusão de um livro e retorna seu número de série
+///
+/// Não existe função de transferência: o emblema pertence ao leitor para sempre.
+pub(crate) fn cunhar_emblema(env: &Env, dono: &Address, livro_id: u32) -> u32 {
+    let serial: u32 = env.storage().instance().get(&DataKey::ContadorEmblemas).unwrap_or(0) + 1;
+
+    let emblema = Emblema {
+        serial,
+        dono: dono.clone(),
+        livro_id,
+        timestamp: env.ledger().timestamp(),
+    };
+
+    env.storage().persistent().set(&DataKey::Emblema(serial), &emblema);
+    env.storage().instance().set(&DataKey::ContadorEmblemas, &serial);
+
+    let key_dono = DataKey::EmblemasDono(dono.clone());
+    let mut emblemas: Vec<u32> = env.storage().persistent().get(&key_dono).unwrap_or_else(|| Vec::new(env));
+    emblemas.push_back(serial);
+    env.storage().persistent().set(&key_dono, &emblemas);
+
+    EmblemaCunhado::publish(
+        &EmblemaCunhado {
+            dono: dono.clone(),
+            livro_id,
+            serial,
+        }, env
+    );
+
+    serial
+}
+
+pub fn obter_emblema(env: Env, serial: u32) -> Option<Emblema> {
+    env.storage().persistent().get(&DataKey::Emblema(serial))
+}
+
+/// Emblema do leitor para um livro, se a recompensa do livro já foi reivindicada
+pub fn emblema_do_livro(env: Env, dono: Address, livro_id: u32) -> Option<Emblema> {
+    let serial: Option<u32> = env.storage()
+        .persistent()
+        .get(&DataKey::RecompensaRecebida(dono, livro_id));
+
+    serial.and_then(|serial| obter_emblema(env, serial))
+}
+
+/// Lista os emblemas de um dono, na ordem em que foram cunhados
+pub fn listar_emblemas(env: Env, dono: Address) -> Vec<Emblema> {
+    let seriais: Vec<u32> = env.storage()
+        .persistent()
+        .get(&DataKey::EmblemasDono(dono))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let mut emblemas = Vec::new(&env);
+    for serial in seriais.iter() {
+        if let Some(emblema) = obter_emblema(env.clone(), serial) {
+            emblemas.push_back(emblema);
+        }
+    }
+
+    emblemas
+}
+
+pub fn total_emblemas(env: Env) -> u32 {
+    env.storage().instance().get(&DataKey::ContadorEmblemas).unwrap_or(0)
+}
+
+/// Define o modelo de URI de metadados dos emblemas
+///
+/// O modelo deve conter o marcador `{id}`, substituído pelo número de série
+/// (ex: `https://exemplo.org/emblemas/{id}.json`).
+pub fn definir_uri_emblemas(env: Env, modelo: String) {
+    if modelo.len() > MAX_URI_CHARS {
+        panic!("URI muito longa");
+    }
+
+    let mut bytes = [0u8; MAX_URI_CHARS as usize];
+    let tamanho = modelo.len() as usize;
+    modelo.copy_into_slice(&mut bytes[..tamanho]);
+
+    if !bytes[..tamanho].windows(MARCADOR_ID.len()).any(|trecho| trecho == MARCADOR_ID) {
+        panic!("Modelo de URI precisa conter o marcador {{id}}");
+    }
+
+    env.storage().instance().set(&DataKey::UriEmblemas, &modelo);
+}
+
+/// URI de metadados de um emblema, montada a partir do modelo definido pelo admin
+pub fn uri_emblema(env: Env, serial: u32) -> String {
+    if !env.storage().persistent().has(&DataKey::Emblema(serial)) {
+        panic!("Emblema não encontrado");
+    }
+
+    let modelo: String = env.storage()
+        .instance()
+        .get(&DataKey::UriEmblemas)
+        .expect("Modelo de URI não definido pelo admin");
+
+    let mut entrada = [0u8; MAX_URI_CHARS as usize];
+    let tamanho = modelo.len() as usize;
+    modelo.copy_into_slice(&mut entrada[..tamanho]);
+
+    let mut digitos = [0u8; 10];
+    let mut quantidade_digitos = 0;
+    let mut restante = serial;
+    loop {
+        digitos[quantidade_digitos] = b'0' + (restante % 10) as u8;
+        quantidade_digitos += 1;
+        restante /= 10;
+        if restante == 0 {
+            break;
+        }
+    }
+    digitos[..quantidade_digitos].reverse();
+
+    let mut saida = [0u8; MAX_URI_CHARS as usize + 10];
+    let mut j = 0;
+    let mut i = 0;
+    let mut substituido = false;
+    while i < tamanho {
+        if !substituido && entrada[i..tamanho].starts_with(MARCADOR_ID) {
+            saida[j..j + quantidade_digitos].copy_from_slice(&digitos[..quantidade_digitos]);
+            j += quantidade_digitos;
+            i += MARCADOR_ID.len();
+            substituido = true;
+        } else {
+            saida[j] = entrada[i];
+            j += 1;
+            i += 1;
+        }
+    }
+
+    String::from_bytes(&env, &saida[..j])
+}
@@ -0,0 +1,189 @@
+//! Módulo responsável pela estrutura canônica dos livros
+//!
+//! O admin registra, para cada livro, quantos versículos existem em cada
+//! capítulo. A partir dessa estrutura o contrato:
+//! - Define a meta de versículos do livro (`EstruturaLivro.total_versiculos`)
+//! - Mantém a lista de livros registrados em cada testamento (`LivrosTestamento`)
+//! - Sabe se o cânon de um testamento está completo (`LivrosCanon`)
+//! - Mantém os totais de versículos por testamento e da Bíblia inteira
+//! - Rejeita referências (`IdTexto`) fora do intervalo registrado
+
+use soroban_sdk::{panic_with_error, Env, Vec};
+use crate::{DataKey, EstruturaLivro, Erro, IdTexto, Testamento};
+
+/// Registra (ou substitui) a estrutura de capítulos e versículos de um livro
+///
+/// `versiculos_por_capitulo[i]` é o total de versículos do capítulo `i + 1`.
+/// A meta do livro passa a ser a soma de todos os capítulos, e os totais do
+/// testamento e da Bíblia são ajustados (descontando um registro anterior).
+/// Se o novo registro remove versículos, a `revisao` do livro aumenta e o
+/// progresso de quem já leu o livro precisa passar por `recontar_progresso`.
+pub fn registrar_estrutura_livro(env: Env, livro_id: u32, testamento: Testamento, versiculos_por_capitulo: Vec<u32>) {
+    if livro_id == 0 {
+        panic!("Livro inválido");
+    }
+
+    if versiculos_por_capitulo.is_empty() {
+        panic!("Livro precisa ter ao menos um capítulo");
+    }
+
+    let mut total_versiculos: u32 = 0;
+    for versiculos in versiculos_por_capitulo.iter() {
+        if versiculos == 0 {
+            panic!("Capítulo precisa ter ao menos um versículo");
+        }
+        total_versiculos += versiculos;
+    }
+
+    let key_estrutura = DataKey::EstruturaLivro(livro_id);
+    let mut revisao: u32 = 0;
+    if let Some(anterior) = env.storage().persistent().get::<DataKey, EstruturaLivro>(&key_estrutura) {
+        revisao = anterior.revisao;
+        if remove_versiculos(&anterior.versiculos_por_capitulo, &versiculos_por_capitulo) {
+            revisao += 1;
+        }
+
+        ajustar_total(&env, DataKey::TotalTestamento(anterior.testamento.clone()), anterior.total_versiculos, 0);
+        ajustar_total(&env, DataKey::TotalBiblia, anterior.total_versiculos, 0);
+        remover_livro_testamento(&env, &anterior.testamento, livro_id);
+    }
+    ajustar_total(&env, DataKey::TotalTestamento(testamento.clone()), 0, total_versiculos);
+    ajustar_total(&env, DataKey::TotalBiblia, 0, total_versiculos);
+    adicionar_livro_testamento(&env, &testamento, livro_id);
+
+    let estrutura = EstruturaLivro {
+        testamento,
+        versiculos_por_capitulo,
+        total_versiculos,
+        revisao,
+    };
+
+    env.storage().persistent().set(&key_estrutura, &estrutura);
+}
+
+/// Indica se algum versículo da estrutura anterior deixa de existir na nova
+fn remove_versiculos(anterior: &Vec<u32>, nova: &Vec<u32>) -> bool {
+    anterior.iter().enumerate().any(|(i, versiculos)| match nova.get(i as u32) {
+        Some(novos) => novos < versiculos,
+        None => true,
+    })
+}
+
+/// Substitui `remover` por `adicionar` no total guardado na chave
+fn ajustar_total(env: &Env, key: DataKey, remover: u32, adicionar: u32) {
+    let total: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(total - remover + adicionar));
+}
+
+fn adicionar_livro_testamento(env: &Env, testamento: &Testamento, livro_id: u32) {
+    let key = DataKey::LivrosTestamento(testamento.clone());
+    let mut livros: Vec<u32> = env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env));
+    livros.push_back(livro_id);
+    env.storage().persistent().set(&key, &livros);
+}
+
+fn remover_livro_testamento(env: &Env, testamento: &Testamento, livro_id: u32) {
+    let key = DataKey::LivrosTestamento(testamento.clone());
+    let mut livros: Vec<u32> = env.storage().persistent().get(&key).unwrap_or_else(|| Vec::new(env));
+    if let Some(posicao) = livros.first_index_of(livro_id) {
+        livros.remove(posicao);
+    }
+    env.storage().persistent().set(&key, &livros);
+}
+
+/// Livros com estrutura registrada no testamento
+pub(crate) fn livros_do_testamento(env: &Env, testamento: &Testamento) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LivrosTestamento(testamento.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+pub fn definir_livros_canon(env: Env, testamento: Testamento, total_livros: u32) {
+    if total_livros == 0 {
+        panic!("O cânon precisa ter ao menos um livro");
+    }
+
+    env.storage().persistent().set(&DataKey::LivrosCanon(testamento), &total_livros);
+}
+
+/// O cânon está completo quando o admin definiu a quantidade de livros esperada
+/// e exatamente essa quantidade de livros tem estrutura registrada no testamento
+pub(crate) fn canon_completo(env: &Env, testamento: &Testamento) -> bool {
+    let esperado: u32 = env.storage()
+        .persistent()
+        .get(&DataKey::LivrosCanon(testamento.clone()))
+        .unwrap_or(0);
+
+    esperado > 0 && livros_do_testamento(env, testamento).len() == esperado
+}
+
+/// Total de versículos do livro, ou None se a estrutura não foi registrada
+pub(crate) fn meta_livro(env: &Env, livro_id: u32) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, EstruturaLivro>(&DataKey::EstruturaLivro(livro_id))
+        .map(|estrutura| estrutura.total_versiculos)
+}
+
+pub fn obter_estrutura_livro(env: Env, livro_id: u32) -> Option<EstruturaLivro> {
+    env.storage().persistent().get(&DataKey::EstruturaLivro(livro_id))
+}
+
+/// Garante que a referência aponta para um versículo existente
+///
+/// Usado por todas as funções públicas que recebem um `IdTexto`,
+/// encerrando a execução com um erro tipado quando a referência é inválida.
+pub fn validar_id_texto(env: &Env, id_texto: &IdTexto) {
+    let estrutura: EstruturaLivro = match env.storage()
+        .persistent()
+        .get(&DataKey::EstruturaLivro(id_texto.livro)) {
+        Some(estrutura) => estrutura,
+        None => panic_with_error!(env, Erro::LivroNaoRegistrado),
+    };
+
+    if id_texto.capitulo == 0 || id_texto.capitulo > estrutura.versiculos_por_capitulo.len() {
+        panic_with_error!(env, Erro::CapituloInvalido);
+    }
+
+    let versiculos = estrutura.versiculos_por_capitulo.get(id_texto.capitulo - 1).unwrap();
+    if id_texto.versiculo == 0 || id_texto.versiculo > versiculos {
+        panic_with_error!(env, Erro::VersiculoInvalido);
+    }
+}
+
+/// Garante que `inicio` e `fim` formam um intervalo válido
+///
+/// As duas referências precisam ser válidas, pertencer ao mesmo livro
+/// e `inicio` não pode vir depois de `fim`. O intervalo pode atravessar capítulos.
+pub fn validar_intervalo(env: &Env, inicio: &IdTexto, fim: &IdTexto) {
+    validar_id_texto(env, inicio);
+    validar_id_texto(env, fim);
+
+    if inicio.livro != fim.livro || inicio > fim {
+        panic_with_error!(env, Erro::IntervaloInvalido);
+    }
+}
+
+/// Percorre, em ordem, todos os versículos entre `inicio` e `fim` (inclusive)
+pub fn para_cada_versiculo<F: FnMut(IdTexto)>(env: &Env, inicio: &IdTexto, fim: &IdTexto, mut f: F) {
+    validar_intervalo(env, inicio, fim);
+
+    let estrutura: EstruturaLivro = env.storage()
+        .persistent()
+        .get(&DataKey::EstruturaLivro(inicio.livro))
+        .unwrap();
+
+    for capitulo in inicio.capitulo..=fim.capitulo {
+        let primeiro = if capitulo == inicio.capitulo { inicio.versiculo } else { 1 };
+        let ultimo = if capitulo == fim.capitulo {
+            fim.versiculo
+        } else {
+            estrutura.versiculos_por_capitulo.get(capitulo - 1).unwrap()
+        };
+
+        for versiculo in primeiro..=ultimo {
+            f(IdTexto { livro: inicio.livro, capitulo, versiculo });
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Módulo responsável pelas indicações de novos leitores
+//!
+//! Um novo leitor pode registrar, uma única vez, quem o indicou. Quando o
+//! indicado reivindica a recompensa do seu primeiro livro, o indicador
+//! recebe um bônus configurável pelo admin. Limites contra abuso:
+//! - Só leitores sem nenhuma leitura registrada podem informar um indicador
+//! - O indicador precisa já ter lido algo e não pode ser o próprio leitor
+//! - Indicações circulares (A indica B e B indica A) são recusadas
+//! - Cada indicador tem um máximo de indicados e de bônus pagos
+//! - O bônus consome o orçamento da época de recompensas e é ignorado se faltar saldo
+
+use soroban_sdk::{contractevent, Env, Address, Vec};
+use crate::{ConfigIndicacao, DataKey, ResumoIndicacoes, MAX_INDICADOS};
+use crate::recompensas::{consumir_orcamento, orcamento_restante};
+
+#[contractevent]
+struct BonusIndicacao {
+    indicador: Address,
+    indicado: Address,
+    valor: u128,
+}
+
+pub fn registrar_indicador(env: Env, leitor: Address, indicador: Address) {
+    leitor.require_auth();
+
+    if leitor == indicador {
+        panic!("Leitor não pode indicar a si mesmo");
+    }
+
+    let key_indicador = DataKey::Indicador(leitor.clone());
+    if env.storage().persistent().has(&key_indicador) {
+        panic!("Indicador já registrado");
+    }
+
+    if env.storage().persistent().has(&DataKey::ContadorHistorico(leitor.clone())) {
+        panic!("Apenas novos leitores podem registrar um indicador");
+    }
+
+    if !env.storage().persistent().has(&DataKey::ContadorHistorico(indicador.clone())) {
+        panic!("Indicador ainda não é um leitor");
+    }
+
+    let indicador_do_indicador: Option<Address> = env.storage()
+        .persistent()
+        .get(&DataKey::Indicador(indicador.clone()));
+    if indicador_do_indicador == Some(leitor.clone()) {
+        panic!("Indicação circular não permitida");
+    }
+
+    let key_indicados = DataKey::Indicados(indicador.clone());
+    let mut indicados: Vec<Address> = env.storage().persistent().get(&key_indicados).unwrap_or_else(|| Vec::new(&env));
+    if indicados.len() >= MAX_INDICADOS {
+        panic!("Indicador atingiu o limite de indicações");
+    }
+
+    indicados.push_back(leitor);
+    env.storage().persistent().set(&key_indicados, &indicados);
+    env.storage().persistent().set(&key_indicador, &indicador);
+}
+
+/// Resumo das indicações de um usuário: quem o indicou, quem ele indicou e bônus recebidos
+pub fn obter_indicacoes(env: Env, usuario: Address) -> ResumoIndicacoes {
+    ResumoIndicacoes {
+        indicador: env.storage().persistent().get(&DataKey::Indicador(usuario.clone())),
+        indicados: env.storage()
+            .persistent()
+            .get(&DataKey::Indicados(usuario.clone()))
+            .unwrap_or_else(|| Vec::new(&env)),
+        bonus_pagos: env.storage()
+            .persistent()
+            .get(&DataKey::BonusIndicacaoPagos(usuario))
+            .unwrap_or(0),
+    }
+}
+
+pub fn configurar_indicacao(env: Env, bonus: u128, max_bonus_por_indicador: u32) {
+    let config = ConfigIndicacao {
+        bonus,
+        max_bonus_por_indicador,
+    };
+    env.storage().instance().set(&DataKey::ConfigIndicacao, &config);
+}
+
+pub fn obter_config_indicacao(env: Env) -> Option<ConfigIndicacao> {
+    env.storage().instance().get(&DataKey::ConfigIndicacao)
+}
+
+/// Paga o bônus ao indicador quando o indicado conclui seu primeiro livro
+///
+/// Chamada por `reivindicar_recompensa_livro` depois de cunhar o emblema.
+/// Nunca faz a reivindicação do indicado falhar: sem configuração, acima
+/// do limite do indicador ou sem orçamento, o bônus simplesmente não é pago.
+pub(crate) fn pagar_bonus_indicacao(env: &Env, indicado: &Address) {
+    let emblemas: Vec<u32> = env.storage()
+        .persistent()
+        .get(&DataKey::EmblemasDono(indicado.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+    if emblemas.len() != 1 {
+        return;
+    }
+
+    let indicador: Address = match env.storage().persistent().get(&DataKey::Indicador(indicado.clone())) {
+        Some(indicador) => indicador,
+        None => return,
+    };
+
+    let config = match obter_config_indicacao(env.clone()) {
+        Some(config) => config,
+        None => return,
+    };
+
+    let key_pagos = DataKey::BonusIndicacaoPagos(indicador.clone());
+    let pagos: u32 = env.storage().persistent().get(&key_pagos).unwrap_or(0);
+    if pagos >= config.max_bonus_por_indicador || config.bonus == 0 {
+        return;
+    }
+
+    if orcamento_restante(env.clone()) < config.bonus {
+        return;
+    }
+
+    consumir_orcamento(env, config.bonus);
+    env.storage().persistent().set(&key_pagos, &(pagos + 1));
+
+    BonusIndicacao::publish(
+        &BonusIndicacao {
+            indicador,
+            indicado: indicado.clone(),
+            valor: config.bonus,
+        }, env
+    );
+}
@@ -0,0 +1,419 @@
+//! Módulo responsável pelo registro de leituras
+//!
+//! Controla a prova de leitura de cada leitor:
+//! - Marca versículos individuais, capítulos inteiros ou intervalos como lidos
+//! - Mantém o progresso de leitura por livro (`ProgressoLeitura`)
+//! - Conta cada versículo uma única vez, independente da tradução usada
+//! - Só aceita versículos com hash registrado na tradução usada
+//! - Guarda quando cada leitura aconteceu e o histórico cronológico do leitor
+//! - Acompanha a sequência de dias consecutivos de leitura
+
+use soroban_sdk::{contractevent, panic_with_error, Env, Address, Bytes, BytesN, Symbol, Vec, Map};
+use crate::{DataKey, EstruturaLivro, Erro, IdTexto, ProgressoLivro, RegistroLeitura, ResultadoLeitura, SequenciaDias};
+use crate::{MARCOS_SEQUENCIA, MAX_LIMITE_PAGINA, MAX_VERSICULOS_LOTE, SEGUNDOS_POR_DIA};
+use crate::{exigir_prova_leitura, exigir_traducao};
+use crate::estrutura::{meta_livro, para_cada_versiculo, validar_id_texto};
+
+#[contractevent]
+struct MarcoSequenciaAlcancado {
+    leitor: Address,
+    dias: u32,
+}
+
+/// Marca um único versículo como lido
+///
+/// Retorna se a leitura é nova, o progresso atualizado do livro e a meta do livro.
+pub fn marcar_lido(env: Env, leitor: Address, id_texto: IdTexto, traducao: Symbol, texto: Option<Bytes>) -> ResultadoLeitura {
+    leitor.require_auth();
+    validar_id_texto(&env, &id_texto);
+    exigir_traducao(&env, &traducao);
+    exigir_prova_leitura(&env, &id_texto, &traducao, texto);
+
+    let livro_id = id_texto.livro;
+    let novas = registrar_leituras(&env, &leitor, livro_id, Vec::from_array(&env, [id_texto]), &traducao);
+    if novas > 0 {
+        atualizar_sequencia(&env, &leitor);
+    }
+
+    ResultadoLeitura {
+        nova_leitura: novas > 0,
+        progresso_livro: env.storage().persistent()
+            .get(&DataKey::ProgressoLeitura(leitor, livro_id))
+            .unwrap_or(0),
+        meta_livro: meta_livro(&env, livro_id),
+    }
+}
+
+/// Marca todos os versículos de um capítulo como lidos em uma única transação
+///
+/// Retorna quantos versículos foram lidos pela primeira vez.
+pub fn marcar_capitulo_lido(
+    env: Env,
+    leitor: Address,
+    livro_id: u32,
+    capitulo: u32,
+    traducao: Symbol,
+    textos: Option<Vec<Bytes>>,
+) -> u32 {
+    let inicio = IdTexto { livro: livro_id, capitulo, versiculo: 1 };
+    validar_id_texto(&env, &inicio);
+
+    let estrutura: EstruturaLivro = env.storage()
+        .persistent()
+        .get(&DataKey::EstruturaLivro(livro_id))
+        .unwrap();
+    let ultimo = estrutura.versiculos_por_capitulo.get(capitulo - 1).unwrap();
+    let fim = IdTexto { livro: livro_id, capitulo, versiculo: ultimo };
+
+    marcar_intervalo_lido(env, leitor, inicio, fim, traducao, textos)
+}
+
+/// Marca todos os versículos entre `inicio` e `fim` (inclusive) como lidos
+///
+/// A operação é atômica: se algum versículo falhar na validação ou na prova
+/// de texto, nada é registrado. O progresso do livro só aumenta pelos
+/// versículos lidos pela primeira vez, que são retornados.
+///
+/// `textos`, quando informado, deve trazer o texto de cada versículo do
+/// intervalo, na mesma ordem (obrigatório no modo estrito).
+pub fn marcar_intervalo_lido(
+    env: Env,
+    leitor: Address,
+    inicio: IdTexto,
+    fim: IdTexto,
+    traducao: Symbol,
+    textos: Option<Vec<Bytes>>,
+) -> u32 {
+    leitor.require_auth();
+    exigir_traducao(&env, &traducao);
+
+    let mut versiculos: Vec<IdTexto> = Vec::new(&env);
+    para_cada_versiculo(&env, &inicio, &fim, |id_texto| versiculos.push_back(id_texto));
+
+    if versiculos.len() > MAX_VERSICULOS_LOTE {
+        panic!("Intervalo excede o limite de versículos por lote");
+    }
+
+    if let Some(textos) = &textos {
+        if textos.len() != versiculos.len() {
+            panic!("Quantidade de textos difere da quantidade de versículos");
+        }
+    }
+
+    for (i, id_texto) in versiculos.iter().enumerate() {
+        let texto = textos.as_ref().map(|textos| textos.get(i as u32).unwrap());
+        exigir_prova_leitura(&env, &id_texto, &traducao, texto);
+    }
+
+    let novas = registrar_leituras(&env, &leitor, inicio.livro, versiculos, &traducao);
+    if novas > 0 {
+        atualizar_sequencia(&env, &leitor);
+    }
+    novas
+}
+
+pub fn verificar_leitura(env: Env, leitor: Address, id_texto: IdTexto) -> bool {
+    validar_id_texto(&env, &id_texto);
+    env.storage().persistent().has(&DataKey::Leitura(leitor, id_texto))
+}
+
+pub fn obter_leitura(env: Env, leitor: Address, id_texto: IdTexto) -> Option<RegistroLeitura> {
+    validar_id_texto(&env, &id_texto);
+    buscar_leitura(&env, &leitor, &id_texto)
+}
+
+/// Registro de leitura de um versículo, lido da chave própria do leitor
+pub(crate) fn buscar_leitura(env: &Env, leitor: &Address, id_texto: &IdTexto) -> Option<RegistroLeitura> {
+    env.storage().persistent().get(&DataKey::Leitura(leitor.clone(), id_texto.clone()))
+}
+
+/// Lista o histórico de leituras de um leitor, da mais antiga para a mais recente
+///
+/// O `limite` é restrito a `MAX_LIMITE_PAGINA` para manter a chamada dentro do orçamento.
+pub fn historico_leitura(env: Env, leitor: Address, limite: u32, offset: u32) -> Vec<RegistroLeitura> {
+    let contador: u32 = env.storage()
+        .persistent()
+        .get(&DataKey::ContadorHistorico(leitor.clone()))
+        .unwrap_or(0);
+
+    let mut historico = Vec::new(&env);
+    let fim = offset.saturating_add(limite.min(MAX_LIMITE_PAGINA)).min(contador);
+
+    for i in offset..fim {
+        if let Some(id_texto) = env.storage()
+            .persistent()
+            .get::<DataKey, IdTexto>(&DataKey::HistoricoLeitura(leitor.clone(), i)) {
+            if let Some(registro) = buscar_leitura(&env, &leitor, &id_texto) {
+                historico.push_back(registro);
+            }
+        }
+    }
+
+    historico
+}
+
+pub fn obter_progresso(env: Env, leitor: Address, livro_id: u32) -> ProgressoLivro {
+    let lidos: u32 = env.storage()
+        .persistent()
+        .get(&DataKey::ProgressoLeitura(leitor.clone(), livro_id))
+        .unwrap_or(0);
+    let meta = meta_livro(&env, livro_id);
+
+    let percentual_bps = match meta {
+        Some(meta) if meta > 0 => ((lidos as u64 * 10_000) / meta as u64).min(10_000) as u32,
+        _ => 0,
+    };
+
+    ProgressoLivro {
+        livro_id,
+        lidos,
+        meta,
+        percentual_bps,
+        recompensa_recebida: env.storage()
+            .persistent()
+            .has(&DataKey::RecompensaRecebida(leitor, livro_id)),
+    }
+}
+
+pub fn obter_progresso_geral(env: Env, leitor: Address) -> Vec<ProgressoLivro> {
+    let livros: Vec<u32> = env.storage()
+        .persistent()
+        .get(&DataKey::LivrosIniciados(leitor.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let mut progresso = Vec::new(&env);
+    for livro_id in livros.iter() {
+        progresso.push_back(obter_progresso(env.clone(), leitor.clone(), livro_id));
+    }
+
+    progresso
+}
+
+/// Recalcula o progresso do leitor em um livro a partir das leituras registradas
+///
+/// Corrige contadores inflados por leituras de versículos inexistentes ou sem
+/// hash, ajustando também os totais do testamento e da Bíblia. Retorna o novo total.
+pub fn recontar_progresso(env: Env, leitor: Address, livro_id: u32) -> u32 {
+    let estrutura: EstruturaLivro = match env.storage()
+        .persistent()
+        .get(&DataKey::EstruturaLivro(livro_id)) {
+        Some(estrutura) => estrutura,
+        None => panic_with_error!(&env, Erro::LivroNaoRegistrado),
+    };
+
+    let cobertura = cobertura_livro(&env, &leitor, livro_id);
+    let mut total: u32 = 0;
+    for (i, lidos) in cobertura.iter().enumerate() {
+        let key_capitulo = DataKey::ProgressoCapitulo(leitor.clone(), livro_id, i as u32 + 1);
+        if lidos > 0 {
+            env.storage().persistent().set(&key_capitulo, &lidos);
+        } else {
+            env.storage().persistent().remove(&key_capitulo);
+        }
+        total += lidos;
+    }
+
+    let key_progresso = DataKey::ProgressoLeitura(leitor.clone(), livro_id);
+    let anterior: u32 = env.storage().persistent().get(&key_progresso).unwrap_or(0);
+    env.storage().persistent().set(&key_progresso, &total);
+
+    if estrutura.revisao > 0 {
+        env.storage().persistent().set(&DataKey::RevisaoProgresso(leitor.clone(), livro_id), &estrutura.revisao);
+    }
+
+    substituir_progresso(&env, DataKey::ProgressoTestamento(leitor.clone(), estrutura.testamento), anterior, total);
+    substituir_progresso(&env, DataKey::ProgressoBiblia(leitor), anterior, total);
+
+    total
+}
+
+/// Indica se os contadores do leitor no livro refletem a estrutura atual
+///
+/// Os contadores são exatos quando gravados, pois só versículos com hash são
+/// aceitos. Eles só ficam inflados quando a estrutura perde versículos depois
+/// das leituras, o que aumenta a `revisao` do livro até a recontagem.
+pub(crate) fn progresso_sincronizado(env: &Env, leitor: &Address, livro_id: u32, estrutura: &EstruturaLivro) -> bool {
+    if estrutura.revisao == 0 {
+        return true;
+    }
+
+    let revisao: u32 = env.storage()
+        .persistent()
+        .get(&DataKey::RevisaoProgresso(leitor.clone(), livro_id))
+        .unwrap_or(0);
+    revisao == estrutura.revisao
+}
+
+/// Conta, por capítulo, os versículos do livro realmente lidos pelo leitor
+///
+/// Considera apenas versículos dentro da estrutura registrada cujo hash existe
+/// na tradução usada na leitura, ignorando os contadores acumulados.
+pub(crate) fn cobertura_livro(env: &Env, leitor: &Address, livro_id: u32) -> Vec<u32> {
+    let mut cobertura: Vec<u32> = Vec::new(env);
+
+    let estrutura: EstruturaLivro = match env.storage()
+        .persistent()
+        .get(&DataKey::EstruturaLivro(livro_id)) {
+        Some(estrutura) => estrutura,
+        None => return cobertura,
+    };
+
+    let hashes: Map<(IdTexto, Symbol), BytesN<32>> = env.storage().instance()
+        .get(&DataKey::Hashes)
+        .unwrap_or_else(|| Map::new(env));
+
+    for (i, versiculos) in estrutura.versiculos_por_capitulo.iter().enumerate() {
+        let capitulo = i as u32 + 1;
+        let mut lidos: u32 = 0;
+        for versiculo in 1..=versiculos {
+            let id_texto = IdTexto { livro: livro_id, capitulo, versiculo };
+            if let Some(registro) = buscar_leitura(env, leitor, &id_texto) {
+                if hashes.contains_key((id_texto, registro.traducao)) {
+                    lidos += 1;
+                }
+            }
+        }
+        cobertura.push_back(lidos);
+    }
+
+    cobertura
+}
+
+pub fn obter_sequencia(env: Env, leitor: Address) -> SequenciaDias {
+    let mut sequencia: SequenciaDias = env.storage()
+        .persistent()
+        .get(&DataKey::SequenciaDias(leitor))
+        .unwrap_or(SequenciaDias { atual: 0, maior: 0, ultimo_dia: 0 });
+
+    let hoje = env.ledger().timestamp() / SEGUNDOS_POR_DIA;
+    if sequencia.ultimo_dia + 1 < hoje {
+        sequencia.atual = 0;
+    }
+
+    sequencia
+}
+
+/// Atualiza a sequência de dias de leitura quando há ao menos um versículo novo
+///
+/// Remarcar versículos já lidos não conta como dia de leitura.
+/// Ler novamente no mesmo dia não altera a sequência; ler no dia seguinte
+/// ao último dia ativo a incrementa; qualquer intervalo maior a reinicia.
+/// Ao atingir um dos `MARCOS_SEQUENCIA` emite `MarcoSequenciaAlcancado`.
+fn atualizar_sequencia(env: &Env, leitor: &Address) {
+    let key = DataKey::SequenciaDias(leitor.clone());
+    let mut sequencia: SequenciaDias = env.storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(SequenciaDias { atual: 0, maior: 0, ultimo_dia: 0 });
+
+    let hoje = env.ledger().timestamp() / SEGUNDOS_POR_DIA;
+    if sequencia.atual > 0 && sequencia.ultimo_dia == hoje {
+        return;
+    }
+
+    if sequencia.atual > 0 && sequencia.ultimo_dia + 1 == hoje {
+        sequencia.atual += 1;
+    } else {
+        sequencia.atual = 1;
+    }
+
+    sequencia.ultimo_dia = hoje;
+    sequencia.maior = sequencia.maior.max(sequencia.atual);
+    env.storage().persistent().set(&key, &sequencia);
+
+    if MARCOS_SEQUENCIA.contains(&sequencia.atual) {
+        MarcoSequenciaAlcancado::publish(
+            &MarcoSequenciaAlcancado {
+                leitor: leitor.clone(),
+                dias: sequencia.atual,
+            }, env
+        );
+    }
+}
+
+/// Grava as leituras ainda não registradas de um mesmo livro
+///
+/// Todos os versículos precisam ter hash registrado na tradução usada.
+/// Cada leitura fica na sua própria chave (`Leitura(leitor, id_texto)`),
+/// então o custo depende só do lote, não de quantas leituras já existem.
+/// O progresso (capítulo, livro, testamento e Bíblia) é incrementado apenas
+/// pelos versículos novos, que também entram no histórico cronológico do leitor.
+fn registrar_leituras(
+    env: &Env,
+    leitor: &Address,
+    livro_id: u32,
+    versiculos: Vec<IdTexto>,
+    traducao: &Symbol,
+) -> u32 {
+    let hashes: Map<(IdTexto, Symbol), BytesN<32>> = env.storage().instance().get(&DataKey::Hashes).unwrap_or_else(|| Map::new(env));
+
+    let key_contador = DataKey::ContadorHistorico(leitor.clone());
+    let contador_inicial: u32 = env.storage().persistent().get(&key_contador).unwrap_or(0);
+
+    let mut novas: u32 = 0;
+    let mut novas_por_capitulo: Map<u32, u32> = Map::new(env);
+    for id_texto in versiculos.iter() {
+        if !hashes.contains_key((id_texto.clone(), traducao.clone())) {
+            panic_with_error!(env, Erro::TextoNaoRegistrado);
+        }
+
+        let key_leitura = DataKey::Leitura(leitor.clone(), id_texto.clone());
+        if !env.storage().persistent().has(&key_leitura) {
+            let registro = RegistroLeitura {
+                id_texto: id_texto.clone(),
+                traducao: traducao.clone(),
+                timestamp: env.ledger().timestamp(),
+                sequencia: env.ledger().sequence(),
+            };
+            env.storage().persistent().set(&key_leitura, &registro);
+
+            env.storage().persistent().set(
+                &DataKey::HistoricoLeitura(leitor.clone(), contador_inicial + novas),
+                &id_texto
+            );
+            novas += 1;
+            novas_por_capitulo.set(id_texto.capitulo, novas_por_capitulo.get(id_texto.capitulo).unwrap_or(0) + 1);
+        }
+    }
+
+    if novas > 0 {
+        env.storage().persistent().set(&key_contador, &(contador_inicial + novas));
+
+        let estrutura: EstruturaLivro = env.storage()
+            .persistent()
+            .get(&DataKey::EstruturaLivro(livro_id))
+            .unwrap();
+
+        let key_progresso = DataKey::ProgressoLeitura(leitor.clone(), livro_id);
+        if !env.storage().persistent().has(&key_progresso) {
+            if estrutura.revisao > 0 {
+                env.storage().persistent().set(&DataKey::RevisaoProgresso(leitor.clone(), livro_id), &estrutura.revisao);
+            }
+
+            let key_livros = DataKey::LivrosIniciados(leitor.clone());
+            let mut livros: Vec<u32> = env.storage().persistent().get(&key_livros).unwrap_or_else(|| Vec::new(env));
+            livros.push_back(livro_id);
+            env.storage().persistent().set(&key_livros, &livros);
+        }
+
+        for (capitulo, quantidade) in novas_por_capitulo.iter() {
+            incrementar_progresso(env, DataKey::ProgressoCapitulo(leitor.clone(), livro_id, capitulo), quantidade);
+        }
+        incrementar_progresso(env, key_progresso, novas);
+        incrementar_progresso(env, DataKey::ProgressoTestamento(leitor.clone(), estrutura.testamento), novas);
+        incrementar_progresso(env, DataKey::ProgressoBiblia(leitor.clone()), novas);
+    }
+
+    novas
+}
+
+fn substituir_progresso(env: &Env, key: DataKey, anterior: u32, novo: u32) {
+    let progresso_atual: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    env.storage().persistent().set(&key, &(progresso_atual.saturating_sub(anterior) + novo));
+}
+
+fn incrementar_progresso(env: &Env, key: DataKey, quantidade: u32) {
+    let mut progresso_atual: u32 = env.storage().persistent().get(&key).unwrap_or(0);
+    progresso_atual += quantidade;
+    env.storage().persistent().set(&key, &progresso_atual);
+}
@@ -1,29 +1,88 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, contractevent, Env, Address, Map, BytesN, String, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, contractevent, Env, Address, Map, Bytes, BytesN, String, Symbol, Vec};
 use core::cmp::Ordering;
 
 mod types;
 mod reflexoes;
+mod estrutura;
+mod leituras;
+mod planos;
+mod recompensas;
+mod emblemas;
+mod indicacoes;
+mod perfis;
+mod seguidores;
+mod tags;
+mod moderacao;
+mod bloqueios;
 
 pub use types::*;
 pub use reflexoes::*;
+pub use estrutura::*;
+pub use leituras::*;
+pub use planos::*;
+pub use recompensas::*;
+pub use emblemas::*;
+pub use indicacoes::*;
+pub use perfis::*;
+pub use seguidores::*;
+pub use tags::*;
+pub use moderacao::*;
+pub use bloqueios::*;
 
 #[contractevent]
-struct RecompensaReivindicada {
-    leitor: Address,
-    livro_id: u32,
-    valor: u128,
+struct HashCorrigido {
+    id_texto: IdTexto,
+    traducao: Symbol,
+    hash_anterior: BytesN<32>,
+    hash_novo: BytesN<32>,
+    motivo: String,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Admin, //  Chave para o endereço do administrador
-    Hashes, // Chave para o mapa de hashes dos textos (Map<IdTexto, BytesN<32>>)
-    Leituras, // Chave para o mapa de leituras Map será Map<(Address, IdTexto), bool>
-    MetaVersiculosLivro(u32), // (Admin) Armazena o total de versículos de um livro (ex: Livro 1 -> 1533)
+    Hashes, // Chave para o mapa de hashes dos textos (Map<(IdTexto, Symbol), BytesN<32>>), um por tradução
+    Leitura(Address, IdTexto), // Registro de leitura de um versículo por um leitor (RegistroLeitura)
+    HistoricoLeitura(Address, u32), // Leituras de um leitor em ordem cronológica (índice -> IdTexto)
+    ContadorHistorico(Address), // Quantidade de leituras no histórico de um leitor
+    SequenciaDias(Address), // Sequência atual e recorde de dias consecutivos de leitura
+    Curador(Address), // (Admin) Endereços autorizados a publicar planos de leitura
+    ContadorPlanos, // Próximo id de plano de leitura
+    Plano(u32), // Metadados de um plano de leitura (PlanoLeitura)
+    DiaPlano(u32, u32), // Intervalos de leitura de um dia do plano (Vec<IntervaloLeitura>)
+    InscricaoPlano(Address, u32), // Inscrição e adesão de um leitor em um plano
+    DiaPlanoConcluido(Address, u32, u32), // Marca um dia do plano como concluído pelo leitor
+    Traducao(Symbol), // (Admin) Metadados de uma tradução (ex: ARA -> nome, idioma, licença)
+    Traducoes, // Lista dos códigos de tradução registrados (Vec<Symbol>)
+    RevisoesHash(IdTexto, Symbol), // Histórico de correções do hash de um texto em uma tradução (Vec<RevisaoHash>)
+    ModoEstrito, // (Admin) Quando true, marcar_lido exige o texto do versículo como prova de leitura
+    EstruturaLivro(u32), // (Admin) Versículos por capítulo de um livro (ex: Livro 1 -> [31, 25, 24, ...])
+    LivrosTestamento(Testamento), // Livros com estrutura registrada em um testamento (Vec<u32>)
+    LivrosCanon(Testamento), // (Admin) Quantidade de livros esperada no cânon de um testamento (ex: Antigo -> 39)
+    RevisaoProgresso(Address, u32), // (Usuário) Revisão da estrutura do livro em que o progresso do leitor foi contado
     ProgressoLeitura(Address, u32), // (Usuário) Conta quantos versículos um leitor leu de um livro (ex: (Josias, Livro 1) -> 500)
-    RecompensaRecebida(Address, u32), // (Sistema) Marca se um leitor JÁ recebeu a recompensa por um livro (ex: (Josias, Livro 1) -> true)
+    LivrosIniciados(Address), // (Usuário) Livros em que o leitor já leu ao menos um versículo (Vec<u32>)
+    ProgressoCapitulo(Address, u32, u32), // (Usuário) Versículos lidos de um capítulo (leitor, livro, capítulo)
+    ProgressoTestamento(Address, Testamento), // (Usuário) Versículos lidos de um testamento
+    ProgressoBiblia(Address), // (Usuário) Versículos lidos de toda a Bíblia
+    TotalTestamento(Testamento), // (Sistema) Soma dos versículos dos livros registrados no testamento
+    TotalBiblia, // (Sistema) Soma dos versículos de todos os livros registrados
+    RecompensaRecebida(Address, u32), // (Sistema) Serial do emblema do leitor para o livro; existe se JÁ recebeu a recompensa (ex: (Josias, Livro 1) -> 42)
+    RecompensaTestamentoRecebida(Address, Testamento), // (Sistema) Marca se um leitor JÁ recebeu a recompensa por um testamento
+    RecompensaBibliaRecebida(Address), // (Sistema) Marca se um leitor JÁ recebeu a recompensa pela Bíblia completa
+    ContadorEpocas, // (Admin) Quantidade de épocas de recompensa criadas; a última é a época atual
+    Epoca(u32), // (Admin) Janela de tempo e orçamento de uma época de recompensas (EpocaRecompensa)
+    RecompensasPausadas, // (Admin) Pausa global das reivindicações de recompensa
+    ContadorEmblemas, // (Sistema) Último número de série de emblema cunhado
+    Emblema(u32), // (Sistema) Emblema de conclusão por número de série
+    EmblemasDono(Address), // (Sistema) Seriais dos emblemas de um leitor (Vec<u32>)
+    UriEmblemas, // (Admin) Modelo de URI de metadados dos emblemas, com o marcador {id}
+    ConfigIndicacao, // (Admin) Valor do bônus de indicação e limite de bônus por indicador
+    Indicador(Address), // (Usuário) Quem indicou o leitor (registrado uma única vez)
+    Indicados(Address), // (Sistema) Leitores indicados por um indicador (Vec<Address>)
+    BonusIndicacaoPagos(Address), // (Sistema) Quantos bônus de indicação um indicador já recebeu
     Reflexoes(IdTexto, Address),
     ContadorReflexoes(IdTexto),
     ReflexoesPublicas(IdTexto, u32),
@@ -32,6 +91,33 @@ pub enum DataKey {
     StatusReflexoes(IdTexto, Address),
 }
 
+/// Chaves das funcionalidades sociais, separadas de `DataKey` para não
+/// ultrapassar o limite de variantes de um enum no contrato
+#[contracttype]
+#[derive(Clone)]
+pub enum ChaveSocial {
+    Perfil(Address), // (Usuário) Perfil público do endereço
+    Apelido(String), // (Sistema) Dono de cada apelido, garante a unicidade
+    TopReflexoes(IdTexto), // (Sistema) Ranking das reflexões mais curtidas do texto (Vec<(Address, u32)>)
+    Tags, // (Admin) Vocabulário de tags aceitas nas reflexões (Vec<String>)
+    ContadorReflexoesTag(String), // (Sistema) Total de reflexões públicas com a tag
+    ReflexaoTag(String, u32), // (Sistema) Índice por tag: posição -> (IdTexto, autor)
+    ContadorComentarios(IdTexto, Address), // (Sistema) Próximo id de comentário da reflexão
+    Moderador(Address), // (Admin) Endereços autorizados a resolver denúncias
+    LimiteOcultacao, // (Admin) Denúncias que colocam uma reflexão em revisão (0 desativa)
+    ContadorDenuncias, // (Sistema) Próximo id de denúncia
+    Denuncia(u32), // (Sistema) Registro de uma denúncia
+    DenunciasAbertas, // (Sistema) Fila de ids das denúncias abertas (Vec<u32>)
+    DenunciasAlvo(AlvoDenuncia), // (Sistema) Ids das denúncias de um item (Vec<u32>)
+    DenunciaFeita(Address, AlvoDenuncia), // (Sistema) Marca que o usuário já denunciou o item
+    HistoricoStatus(IdTexto, Address), // (Sistema) Transições de status da reflexão (Vec<RegistroStatusReflexao>)
+    Bloqueados(Address), // (Usuário) Endereços impedidos de curtir e comentar suas reflexões (Vec<Address>)
+    Seguindo(Address), // (Usuário) Leitores seguidos, na ordem em que foram seguidos (Vec<Address>)
+    ContagemSeguidores(Address), // (Sistema) Quantos leitores seguem o endereço
+    ContadorReflexoesAutor(Address), // (Sistema) Total de reflexões do autor, públicas ou privadas
+    ReflexaoAutor(Address, u32), // (Sistema) Índice por autor: posição -> IdTexto da reflexão
+}
+
 #[contract]
 pub struct ContratoBiblia;
 
@@ -44,77 +130,194 @@ impl ContratoBiblia {
         env.storage().instance().set(&DataKey::Admin, &admin);
     }
 
-    pub fn registrar_hash(env: Env, id_texto: IdTexto, hash: BytesN<32>) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// (Admin) Registra ou atualiza os metadados de uma tradução da Bíblia.
+    /// O código é um símbolo curto (ex: ARA, NVI, ACF, KJV) usado como chave dos hashes.
+    pub fn registrar_traducao(env: Env, codigo: Symbol, nome: String, idioma: String, licenca: String) {
+        exigir_admin(&env);
+
+        let traducao = Traducao {
+            codigo: codigo.clone(),
+            nome,
+            idioma,
+            licenca,
+        };
+        env.storage().persistent().set(&DataKey::Traducao(codigo.clone()), &traducao);
+
+        let mut traducoes: Vec<Symbol> = env.storage().instance().get(&DataKey::Traducoes).unwrap_or_else(|| Vec::new(&env));
+        if !traducoes.contains(&codigo) {
+            traducoes.push_back(codigo);
+            env.storage().instance().set(&DataKey::Traducoes, &traducoes);
+        }
+    }
 
-        let mut hashes: Map<IdTexto, BytesN<32>> = env.storage().instance().get(&DataKey::Hashes).unwrap_or_else(|| Map::new(&env));
+    /// Obtém os metadados de uma tradução registrada
+    pub fn obter_traducao(env: Env, codigo: Symbol) -> Option<Traducao> {
+        env.storage().persistent().get(&DataKey::Traducao(codigo))
+    }
+
+    /// Lista os códigos de todas as traduções registradas
+    pub fn listar_traducoes(env: Env) -> Vec<Symbol> {
+        env.storage().instance().get(&DataKey::Traducoes).unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// (Admin) Registra o hash oficial de um texto em uma tradução.
+    /// Recusa sobrescrever um hash existente: para isso use `corrigir_hash`.
+    pub fn registrar_hash(env: Env, id_texto: IdTexto, traducao: Symbol, hash: BytesN<32>) {
+        exigir_admin(&env);
+        validar_id_texto(&env, &id_texto);
+        exigir_traducao(&env, &traducao);
+
+        let mut hashes: Map<(IdTexto, Symbol), BytesN<32>> = env.storage().instance().get(&DataKey::Hashes).unwrap_or_else(|| Map::new(&env));
+
+        let chave = (id_texto, traducao);
+        if hashes.contains_key(chave.clone()) {
+            panic!("Hash já registrado para este texto. Use corrigir_hash");
+        }
 
-        hashes.set(id_texto, hash);
+        hashes.set(chave, hash);
         env.storage().instance().set(&DataKey::Hashes, &hashes);
     }
 
-    pub fn verificar_texto(env: Env, id_texto: IdTexto, texto: soroban_sdk::Bytes) -> bool {
-        let hash_calculado: BytesN<32> = env.crypto().sha256(&texto).into();
+    /// (Admin) Substitui o hash de um texto já registrado, exigindo um motivo.
+    /// A correção é guardada no histórico de revisões e emite o evento `HashCorrigido`.
+    pub fn corrigir_hash(env: Env, id_texto: IdTexto, traducao: Symbol, hash: BytesN<32>, motivo: String) {
+        exigir_admin(&env);
+        validar_id_texto(&env, &id_texto);
 
-        let hashes: Map<IdTexto, BytesN<32>> = env.storage().instance()
-            .get(&DataKey::Hashes)
-            .unwrap_or_else(|| Map::new(&env));
+        if motivo.is_empty() {
+            panic!("Motivo da correção é obrigatório");
+        }
+
+        let mut hashes: Map<(IdTexto, Symbol), BytesN<32>> = env.storage().instance().get(&DataKey::Hashes).unwrap_or_else(|| Map::new(&env));
 
-        if let Some(hash_oficial) = hashes.get(id_texto) {
-            hash_oficial.cmp(&hash_calculado) == Ordering::Equal
-        } else {
-            false
+        let chave = (id_texto.clone(), traducao.clone());
+        let hash_anterior = hashes.get(chave.clone()).expect("Hash não registrado para este texto");
+        if hash_anterior == hash {
+            panic!("Novo hash é igual ao hash atual");
         }
+
+        hashes.set(chave, hash.clone());
+        env.storage().instance().set(&DataKey::Hashes, &hashes);
+
+        let key_revisoes = DataKey::RevisoesHash(id_texto.clone(), traducao.clone());
+        let mut revisoes: Vec<RevisaoHash> = env.storage().persistent().get(&key_revisoes).unwrap_or_else(|| Vec::new(&env));
+        revisoes.push_back(RevisaoHash {
+            hash_anterior: hash_anterior.clone(),
+            hash_novo: hash.clone(),
+            motivo: motivo.clone(),
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&key_revisoes, &revisoes);
+
+        HashCorrigido::publish(
+            &HashCorrigido {
+                id_texto,
+                traducao,
+                hash_anterior,
+                hash_novo: hash,
+                motivo,
+            }, &env
+        );
     }
 
-    pub fn marcar_lido(env: Env, leitor: Address, id_texto: IdTexto) {
-        leitor.require_auth();
+    /// Lista as correções feitas no hash de um texto, da mais antiga para a mais recente
+    pub fn obter_revisoes_hash(env: Env, id_texto: IdTexto, traducao: Symbol) -> Vec<RevisaoHash> {
+        validar_id_texto(&env, &id_texto);
 
-        let key_leitura = (leitor.clone(), id_texto.clone());
+        env.storage().persistent()
+            .get(&DataKey::RevisoesHash(id_texto, traducao))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
 
-        let mut leituras: Map<(Address, IdTexto), bool> = env.storage().instance().get(&DataKey::Leituras).unwrap_or_else(|| Map::new(&env));
+    pub fn verificar_texto(env: Env, id_texto: IdTexto, traducao: Symbol, texto: Bytes) -> bool {
+        validar_id_texto(&env, &id_texto);
+        texto_confere(&env, id_texto, traducao, &texto)
+    }
 
-        if !leituras.get(key_leitura.clone()).unwrap_or(false) {
-            leituras.set(key_leitura, true);
-            env.storage().instance().set(&DataKey::Leituras, &leituras);
+    /// (Admin) Ativa ou desativa o modo estrito de leitura.
+    /// No modo estrito, `marcar_lido` só conta o versículo se receber o texto que confere com o hash registrado.
+    pub fn definir_modo_estrito(env: Env, ativo: bool) {
+        exigir_admin(&env);
+        env.storage().instance().set(&DataKey::ModoEstrito, &ativo);
+    }
 
-            let livro_id = id_texto.livro;
-            let key_progresso = DataKey::ProgressoLeitura(leitor.clone(), livro_id);
+    pub fn modo_estrito(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::ModoEstrito).unwrap_or(false)
+    }
 
-            let mut progresso_atual: u32 = env.storage().persistent().get(&key_progresso).unwrap_or(0);
-            progresso_atual += 1;
-            env.storage().persistent().set(&key_progresso, &progresso_atual);
-            String::from_str(&env, "Leitura registrada e progresso atualizado!");
-        } else {
-            String::from_str(&env, "Este versículo já foi marcado como lido.");
-        }
+    /// Marca um versículo como lido pelo leitor, registrando a tradução usada na leitura.
+    /// O progresso do livro conta cada versículo uma única vez, independente da tradução.
+    /// O `texto` é a prova de posse do versículo: obrigatório no modo estrito e,
+    /// quando informado, sempre conferido contra o hash registrado.
+    /// Retorna um `ResultadoLeitura` indicando se a leitura é nova, o progresso do livro e sua meta.
+    pub fn marcar_lido(env: Env, leitor: Address, id_texto: IdTexto, traducao: Symbol, texto: Option<Bytes>) -> ResultadoLeitura {
+        leituras::marcar_lido(env, leitor, id_texto, traducao, texto)
     }
 
-    pub fn verificar_leitura(env: Env, leitor: Address, id_texto: IdTexto) -> String {
-        let leituras: Map<(Address, IdTexto), bool> = env.storage().instance()
-            .get(&DataKey::Leituras)
-            .unwrap_or_else(|| Map::new(&env));
-        if let Some(true) = leituras.get((leitor, id_texto)) {
-            String::from_str(&env, "Leitura confirmada!")
-        } else {
-            String::from_str(&env, "Registro de leitura não encontrado.")
-        }
+    /// Marca todos os versículos de um capítulo como lidos em uma única transação.
+    /// Retorna quantos versículos foram lidos pela primeira vez.
+    pub fn marcar_capitulo_lido(
+        env: Env,
+        leitor: Address,
+        livro_id: u32,
+        capitulo: u32,
+        traducao: Symbol,
+        textos: Option<Vec<Bytes>>,
+    ) -> u32 {
+        leituras::marcar_capitulo_lido(env, leitor, livro_id, capitulo, traducao, textos)
+    }
+
+    /// Marca todos os versículos entre `inicio` e `fim` (inclusive, mesmo livro) como lidos.
+    /// Atômico: ou todos são registrados, ou nenhum. O progresso só conta versículos novos.
+    pub fn marcar_intervalo_lido(
+        env: Env,
+        leitor: Address,
+        inicio: IdTexto,
+        fim: IdTexto,
+        traducao: Symbol,
+        textos: Option<Vec<Bytes>>,
+    ) -> u32 {
+        leituras::marcar_intervalo_lido(env, leitor, inicio, fim, traducao, textos)
+    }
+
+    /// Retorna true se o leitor já marcou o versículo como lido
+    pub fn verificar_leitura(env: Env, leitor: Address, id_texto: IdTexto) -> bool {
+        leituras::verificar_leitura(env, leitor, id_texto)
+    }
+
+    /// Obtém o registro de leitura de um versículo (tradução, timestamp e ledger), se existir
+    pub fn obter_leitura(env: Env, leitor: Address, id_texto: IdTexto) -> Option<RegistroLeitura> {
+        leituras::obter_leitura(env, leitor, id_texto)
+    }
+
+    /// Obtém a sequência de dias consecutivos de leitura do leitor.
+    /// Se o leitor não leu ontem nem hoje, a sequência atual é retornada como 0.
+    pub fn obter_sequencia(env: Env, leitor: Address) -> SequenciaDias {
+        leituras::obter_sequencia(env, leitor)
+    }
+
+    /// Lista as leituras de um leitor em ordem cronológica, com paginação
+    pub fn historico_leitura(env: Env, leitor: Address, limite: u32, offset: u32) -> Vec<RegistroLeitura> {
+        leituras::historico_leitura(env, leitor, limite, offset)
     }
 
     // Permite que os usuários adicionarem reflexões pessoais sobre textos bíblicos
     // pode ser pública (visível para todos) ou privada (apenas para o autor)
     // Requer que o usuário tenha marcado o texto como lido
     // Cada usuário pode ter apenas uma reflexão por texto 
+    // A tradução indica qual versão do texto inspirou a reflexão
+    // As tags (até 3) vêm do vocabulário registrado pelo admin
 
     pub fn adicionar_reflexao(
         env: Env,
         leitor: Address,
         id_texto: IdTexto,
+        traducao: Symbol,
         conteudo: String,
         publica: bool,
+        tags: Vec<String>,
     ) {
-        reflexoes::adicionar_reflexao(env, leitor, id_texto, conteudo, publica)
+        reflexoes::adicionar_reflexao(env, leitor, id_texto, traducao, conteudo, publica, tags)
     }
 
     // Obtém uma reflexão específica de um usuário sobre um texto
@@ -127,16 +330,174 @@ impl ContratoBiblia {
         reflexoes::obter_reflexao(env, leitor, id_texto)
     }
 
-    /// Lista reflexões públicas de um texto específico com paginação
+    /// Lista reflexões públicas de um texto específico com paginação por cursor
     /// Permite navegação através de grandes volumes de reflexões
-    /// Filtra automaticamente reflexões removidas ou privadas
+    /// Filtra automaticamente reflexões removidas ou privadas sem encurtar a página
     pub fn listar_reflexoes_publicas(
         env: Env,
         id_texto: IdTexto,
+        ordem: OrdemReflexoes,
+        limite: u32,
+        cursor: Option<u32>,
+    ) -> PaginaReflexoes {
+        reflexoes::listar_reflexoes_publicas(env, id_texto, ordem, limite, cursor)
+    }
+
+    /// Mesma listagem de `listar_reflexoes_publicas`, trazendo apelido e avatar de cada autor
+    pub fn listar_reflexoes_com_perfil(
+        env: Env,
+        id_texto: IdTexto,
+        ordem: OrdemReflexoes,
+        limite: u32,
+        cursor: Option<u32>,
+    ) -> PaginaReflexoesComPerfil {
+        perfis::listar_reflexoes_com_perfil(env, id_texto, ordem, limite, cursor)
+    }
+
+    /// Lista as reflexões de um autor em todos os textos (seu diário de reflexões)
+    /// Com `incluir_privadas`, exige a autenticação do autor e traz também as privadas
+    /// Sem elas, combinada com `listar_seguindo`, permite montar o feed de um leitor
+    pub fn listar_reflexoes_do_autor(
+        env: Env,
+        autor: Address,
+        incluir_privadas: bool,
         limite: u32,
         offset: u32,
     ) -> Vec<Reflexao> {
-        reflexoes::listar_reflexoes_publicas(env, id_texto, limite, offset)
+        reflexoes::listar_reflexoes_do_autor(env, autor, incluir_privadas, limite, offset)
+    }
+
+    /// (Admin) Autoriza um endereço a resolver denúncias
+    pub fn adicionar_moderador(env: Env, moderador: Address) {
+        exigir_admin(&env);
+        env.storage().persistent().set(&ChaveSocial::Moderador(moderador), &true);
+    }
+
+    /// (Admin) Revoga a autorização de um moderador
+    pub fn remover_moderador(env: Env, moderador: Address) {
+        exigir_admin(&env);
+        env.storage().persistent().remove(&ChaveSocial::Moderador(moderador));
+    }
+
+    /// (Admin) Define quantas denúncias colocam uma reflexão em revisão (0 desativa)
+    pub fn definir_limite_ocultacao(env: Env, limite: u32) {
+        exigir_admin(&env);
+        moderacao::definir_limite_ocultacao(env, limite)
+    }
+
+    pub fn obter_limite_ocultacao(env: Env) -> u32 {
+        moderacao::obter_limite_ocultacao(env)
+    }
+
+    /// Denuncia uma reflexão pública ou um comentário, uma vez por usuário e item.
+    /// Retorna o id da denúncia.
+    pub fn denunciar(env: Env, denunciante: Address, alvo: AlvoDenuncia, motivo: MotivoDenuncia) -> u32 {
+        moderacao::denunciar(env, denunciante, alvo, motivo)
+    }
+
+    pub fn obter_denuncia(env: Env, denuncia_id: u32) -> Option<Denuncia> {
+        moderacao::obter_denuncia(env, denuncia_id)
+    }
+
+    /// Fila de denúncias abertas, da mais antiga para a mais recente, com paginação
+    pub fn listar_denuncias_abertas(env: Env, limite: u32, offset: u32) -> Vec<Denuncia> {
+        moderacao::listar_denuncias_abertas(env, limite, offset)
+    }
+
+    /// (Admin ou moderador) Resolve as denúncias abertas do item denunciado.
+    /// Procedente remove o conteúdo; improcedente devolve a reflexão em revisão ao estado ativo.
+    pub fn resolver_denuncia(env: Env, moderador: Address, denuncia_id: u32, procedente: bool) {
+        moderacao::resolver_denuncia(env, moderador, denuncia_id, procedente)
+    }
+
+    /// Altera o status de uma reflexão registrando ator, data e motivo.
+    /// O autor pode ocultar, reexibir ou remover a sua; moderadores podem
+    /// colocar em revisão, remover e restaurar.
+    pub fn alterar_status_reflexao(
+        env: Env,
+        ator: Address,
+        id_texto: IdTexto,
+        autor_reflexao: Address,
+        novo_status: StatusReflexao,
+        motivo: String,
+    ) {
+        moderacao::alterar_status_reflexao(env, ator, id_texto, autor_reflexao, novo_status, motivo)
+    }
+
+    pub fn historico_status_reflexao(env: Env, id_texto: IdTexto, autor_reflexao: Address) -> Vec<RegistroStatusReflexao> {
+        moderacao::historico_status_reflexao(env, id_texto, autor_reflexao)
+    }
+
+    /// (Admin) Adiciona uma tag ao vocabulário aceito nas reflexões
+    pub fn registrar_tag(env: Env, tag: String) {
+        exigir_admin(&env);
+        tags::registrar_tag(env, tag)
+    }
+
+    pub fn listar_tags(env: Env) -> Vec<String> {
+        tags::listar_tags(env)
+    }
+
+    /// Lista reflexões públicas de uma tag em todos os livros, com paginação por cursor
+    pub fn listar_reflexoes_por_tag(env: Env, tag: String, limite: u32, cursor: Option<u32>) -> PaginaReflexoes {
+        tags::listar_reflexoes_por_tag(env, tag, limite, cursor)
+    }
+
+    /// Impede um endereço de curtir e comentar as reflexões do usuário
+    pub fn bloquear(env: Env, usuario: Address, bloqueado: Address) {
+        bloqueios::bloquear(env, usuario, bloqueado)
+    }
+
+    pub fn desbloquear(env: Env, usuario: Address, bloqueado: Address) {
+        bloqueios::desbloquear(env, usuario, bloqueado)
+    }
+
+    pub fn listar_bloqueados(env: Env, usuario: Address) -> Vec<Address> {
+        bloqueios::listar_bloqueados(env, usuario)
+    }
+
+    pub fn seguir(env: Env, seguidor: Address, seguido: Address) {
+        seguidores::seguir(env, seguidor, seguido)
+    }
+
+    pub fn deixar_de_seguir(env: Env, seguidor: Address, seguido: Address) {
+        seguidores::deixar_de_seguir(env, seguidor, seguido)
+    }
+
+    pub fn segue(env: Env, seguidor: Address, seguido: Address) -> bool {
+        seguidores::segue(env, seguidor, seguido)
+    }
+
+    /// Lista, com paginação, os leitores que um leitor segue
+    pub fn listar_seguindo(env: Env, leitor: Address, limite: u32, offset: u32) -> Vec<Address> {
+        seguidores::listar_seguindo(env, leitor, limite, offset)
+    }
+
+    pub fn obter_contagem_seguidores(env: Env, leitor: Address) -> ContagemSeguidores {
+        seguidores::obter_contagem_seguidores(env, leitor)
+    }
+
+    /// Cria ou atualiza o perfil público do usuário (apelido único, bio, avatar,
+    /// idioma e tradução preferida)
+    pub fn definir_perfil(
+        env: Env,
+        usuario: Address,
+        apelido: String,
+        bio: String,
+        hash_avatar: Option<BytesN<32>>,
+        idioma: String,
+        traducao_preferida: Option<Symbol>,
+    ) {
+        perfis::definir_perfil(env, usuario, apelido, bio, hash_avatar, idioma, traducao_preferida)
+    }
+
+    pub fn obter_perfil(env: Env, usuario: Address) -> Option<Perfil> {
+        perfis::obter_perfil(env, usuario)
+    }
+
+    /// Busca o endereço e o perfil do dono de um apelido
+    pub fn obter_perfil_por_apelido(env: Env, apelido: String) -> Option<(Address, Perfil)> {
+        perfis::obter_perfil_por_apelido(env, apelido)
     }
 
     /// Sistema de engajamento: permite curtir/descurtir reflexões públicas
@@ -164,81 +525,302 @@ impl ContratoBiblia {
         reflexoes::comentar_reflexao(env, comentarista, id_texto, autor_reflexao, conteudo)
     }
 
-    /// Remove um comentário específico do usuário
+    /// Remove um comentário específico do usuário, pelo `id` do comentário
     /// Apenas o autor do comentário pode removê-lo
     pub fn remover_comentario(
         env: Env,
         usuario: Address,
         id_texto: IdTexto,
         autor_reflexao: Address,
-        indice_comentario: u32,
+        comentario_id: u32,
     ) {
-        reflexoes::remover_comentario(env, usuario, id_texto, autor_reflexao, indice_comentario)
+        reflexoes::remover_comentario(env, usuario, id_texto, autor_reflexao, comentario_id)
     }
 
     /// Obtém todos os comentários de uma reflexão específica
     /// Retorna lista ordenada cronologicamente
+    /// Os ocultos só aparecem para o autor da reflexão ou moderadores, informados em `solicitante`
     pub fn obter_comentarios(
         env: Env,
         id_texto: IdTexto,
         autor_reflexao: Address,
+        solicitante: Option<Address>,
     ) -> Vec<Comentario> {
-        reflexoes::obter_comentarios(env, id_texto, autor_reflexao)
+        reflexoes::obter_comentarios(env, id_texto, autor_reflexao, solicitante)
     }
 
-    /// Verifica o status atual de uma reflexão (ativa ou removida)
+    /// (Autor da reflexão ou moderador) Oculta um comentário, registrando qual papel o ocultou
+    pub fn ocultar_comentario(
+        env: Env,
+        ator: Address,
+        id_texto: IdTexto,
+        autor_reflexao: Address,
+        comentario_id: u32,
+    ) {
+        moderacao::ocultar_comentario(env, ator, id_texto, autor_reflexao, comentario_id)
+    }
+
+    /// Reexibe um comentário oculto (moderadores, ou o autor da reflexão se foi ele quem ocultou)
+    pub fn reexibir_comentario(
+        env: Env,
+        ator: Address,
+        id_texto: IdTexto,
+        autor_reflexao: Address,
+        comentario_id: u32,
+    ) {
+        moderacao::reexibir_comentario(env, ator, id_texto, autor_reflexao, comentario_id)
+    }
+
+    /// Verifica o status atual de uma reflexão, com quem o definiu, quando e por quê
     /// Usado para controle de moderação e visibilidade
     pub fn verificar_status_reflexao(
         env: Env,
         id_texto: IdTexto,
         autor_reflexao: Address,
-    ) -> StatusReflexao {
+    ) -> RegistroStatusReflexao {
         reflexoes::verificar_status_reflexao(env, id_texto, autor_reflexao)
     }
 
 
-    /// (Admin) Define o número total de versículos de um livro.
-    /// Ex: livro 1 (Gênesis) tem 1533 versículos.
-    pub fn registrar_meta_livro(env: Env, livro_id: u32, total_versiculos: u32) {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    /// (Admin) Autoriza um endereço a publicar planos de leitura
+    pub fn adicionar_curador(env: Env, curador: Address) {
+        exigir_admin(&env);
+        env.storage().persistent().set(&DataKey::Curador(curador), &true);
+    }
+
+    /// (Admin) Revoga a autorização de um curador
+    pub fn remover_curador(env: Env, curador: Address) {
+        exigir_admin(&env);
+        env.storage().persistent().remove(&DataKey::Curador(curador));
+    }
+
+    /// (Admin ou curador) Cria um plano de leitura vazio e retorna seu id.
+    /// As tarefas diárias são adicionadas com `adicionar_dias_plano`.
+    pub fn criar_plano(env: Env, criador: Address, nome: String, duracao_dias: u32) -> u32 {
+        planos::criar_plano(env, criador, nome, duracao_dias)
+    }
+
+    /// Acrescenta as tarefas dos próximos dias do plano (cada dia é uma lista de intervalos)
+    pub fn adicionar_dias_plano(env: Env, criador: Address, plano_id: u32, dias: Vec<Vec<IntervaloLeitura>>) {
+        planos::adicionar_dias_plano(env, criador, plano_id, dias)
+    }
+
+    pub fn obter_plano(env: Env, plano_id: u32) -> Option<PlanoLeitura> {
+        planos::obter_plano(env, plano_id)
+    }
+
+    /// Inscreve o leitor em um plano começando na data de `inicio` (timestamp)
+    pub fn inscrever_plano(env: Env, leitor: Address, plano_id: u32, inicio: u64) {
+        planos::inscrever_plano(env, leitor, plano_id, inicio)
+    }
+
+    /// Retorna a tarefa de hoje do leitor no plano (None fora do período do plano)
+    pub fn tarefa_do_dia(env: Env, leitor: Address, plano_id: u32) -> Option<TarefaDia> {
+        planos::tarefa_do_dia(env, leitor, plano_id)
+    }
+
+    /// Conclui um dia do plano, verificando as leituras já registradas
+    pub fn concluir_dia_plano(env: Env, leitor: Address, plano_id: u32, dia: u32) {
+        planos::concluir_dia_plano(env, leitor, plano_id, dia)
+    }
+
+    /// Resumo da adesão do leitor ao plano: dias decorridos, concluídos e concluídos no prazo
+    pub fn progresso_plano(env: Env, leitor: Address, plano_id: u32) -> ProgressoPlano {
+        planos::progresso_plano(env, leitor, plano_id)
+    }
+
+    /// Emite o evento de recompensa quando todos os dias do plano foram concluídos
+    pub fn reivindicar_recompensa_plano(env: Env, leitor: Address, plano_id: u32) {
+        planos::reivindicar_recompensa_plano(env, leitor, plano_id)
+    }
+
+    /// (Admin) Registra a estrutura canônica de um livro: testamento e versículos por capítulo.
+    /// Também define a meta do livro como a soma dos versículos.
+    /// Referências fora dessa estrutura são rejeitadas com erros tipados (`Erro`).
+    pub fn registrar_estrutura_livro(env: Env, livro_id: u32, testamento: Testamento, versiculos_por_capitulo: Vec<u32>) {
+        exigir_admin(&env);
+        estrutura::registrar_estrutura_livro(env, livro_id, testamento, versiculos_por_capitulo)
+    }
+
+    /// Progresso do leitor em um livro: lidos, meta, percentual (pontos-base) e recompensa
+    pub fn obter_progresso(env: Env, leitor: Address, livro_id: u32) -> ProgressoLivro {
+        leituras::obter_progresso(env, leitor, livro_id)
+    }
+
+    /// (Admin) Recalcula o progresso do leitor em um livro a partir das leituras reais.
+    /// Retorna o novo total de versículos lidos.
+    pub fn recontar_progresso(env: Env, leitor: Address, livro_id: u32) -> u32 {
+        exigir_admin(&env);
+        leituras::recontar_progresso(env, leitor, livro_id)
+    }
+
+    /// Progresso do leitor em cada livro que ele já começou, na ordem em que começou
+    pub fn obter_progresso_geral(env: Env, leitor: Address) -> Vec<ProgressoLivro> {
+        leituras::obter_progresso_geral(env, leitor)
+    }
+
+    /// Versículos lidos pelo leitor em um capítulo
+    pub fn obter_progresso_capitulo(env: Env, leitor: Address, livro_id: u32, capitulo: u32) -> u32 {
+        env.storage().persistent().get(&DataKey::ProgressoCapitulo(leitor, livro_id, capitulo)).unwrap_or(0)
+    }
+
+    /// Versículos lidos pelo leitor em um testamento
+    pub fn obter_progresso_testamento(env: Env, leitor: Address, testamento: Testamento) -> u32 {
+        env.storage().persistent().get(&DataKey::ProgressoTestamento(leitor, testamento)).unwrap_or(0)
+    }
 
-        let key = DataKey::MetaVersiculosLivro(livro_id);
-        env.storage().persistent().set(&key, &total_versiculos);
+    /// Versículos lidos pelo leitor em toda a Bíblia
+    pub fn obter_progresso_biblia(env: Env, leitor: Address) -> u32 {
+        env.storage().persistent().get(&DataKey::ProgressoBiblia(leitor)).unwrap_or(0)
+    }
+
+    /// Obtém a estrutura registrada de um livro, se existir
+    pub fn obter_estrutura_livro(env: Env, livro_id: u32) -> Option<EstruturaLivro> {
+        estrutura::obter_estrutura_livro(env, livro_id)
+    }
+
+    /// (Admin) Define quantos livros o cânon de um testamento deve ter (ex: Antigo -> 39, Novo -> 27).
+    /// As recompensas de testamento e da Bíblia só são liberadas com o cânon completo.
+    pub fn definir_livros_canon(env: Env, testamento: Testamento, total_livros: u32) {
+        exigir_admin(&env);
+        estrutura::definir_livros_canon(env, testamento, total_livros)
+    }
+
+    /// Indica se todos os livros esperados do testamento já têm estrutura registrada
+    pub fn canon_completo(env: Env, testamento: Testamento) -> bool {
+        estrutura::canon_completo(&env, &testamento)
+    }
+
+    /// (Admin) Cria uma época de recompensas com início, fim (timestamps) e orçamento total.
+    /// A nova época substitui a anterior como época atual. Retorna o id da época.
+    pub fn criar_epoca_recompensa(env: Env, inicio: u64, fim: u64, orcamento: u128) -> u32 {
+        exigir_admin(&env);
+        recompensas::criar_epoca_recompensa(env, inicio, fim, orcamento)
+    }
+
+    pub fn obter_epoca_atual(env: Env) -> Option<EpocaRecompensa> {
+        recompensas::obter_epoca_atual(env)
+    }
+
+    /// Orçamento ainda disponível na época de recompensas atual
+    pub fn orcamento_restante(env: Env) -> u128 {
+        recompensas::orcamento_restante(env)
+    }
+
+    /// (Admin) Pausa ou retoma todas as reivindicações de recompensa
+    pub fn pausar_recompensas(env: Env, pausado: bool) {
+        exigir_admin(&env);
+        env.storage().instance().set(&DataKey::RecompensasPausadas, &pausado);
     }
 
     pub fn reivindicar_recompensa_livro(env: Env, leitor: Address, livro_id: u32) {
-        leitor.require_auth();
+        recompensas::reivindicar_recompensa_livro(env, leitor, livro_id)
+    }
 
-        let key_recompensa = DataKey::RecompensaRecebida(leitor.clone(), livro_id);
-        if env.storage().persistent().has(&key_recompensa) {
-            panic!("Recompensa por este livro já foi recebida!");
-        }
+    pub fn obter_emblema(env: Env, serial: u32) -> Option<Emblema> {
+        emblemas::obter_emblema(env, serial)
+    }
+
+    /// Emblema de conclusão do leitor para um livro, se a recompensa já foi reivindicada
+    pub fn emblema_do_livro(env: Env, dono: Address, livro_id: u32) -> Option<Emblema> {
+        emblemas::emblema_do_livro(env, dono, livro_id)
+    }
+
+    /// Lista os emblemas de um leitor, na ordem em que foram cunhados
+    pub fn listar_emblemas(env: Env, dono: Address) -> Vec<Emblema> {
+        emblemas::listar_emblemas(env, dono)
+    }
 
-        let key_progresso = DataKey::ProgressoLeitura(leitor.clone(), livro_id);
-        let progresso_atual: u32 = env.storage().persistent().get(&key_progresso).unwrap_or(0);
+    /// Quantidade total de emblemas cunhados (o maior número de série)
+    pub fn total_emblemas(env: Env) -> u32 {
+        emblemas::total_emblemas(env)
+    }
 
-        let key_meta = DataKey::MetaVersiculosLivro(livro_id);
-        let meta_total: u32 = env.storage().persistent().get(&key_meta)
-            .expect("Meta para este livro não foi definida pelo admin");
+    /// (Admin) Define o modelo de URI de metadados dos emblemas, que deve conter `{id}`
+    pub fn definir_uri_emblemas(env: Env, modelo: String) {
+        exigir_admin(&env);
+        emblemas::definir_uri_emblemas(env, modelo)
+    }
 
-        if progresso_atual < meta_total {
-            panic!("Livro ainda não concluído. Continue lendo!");
-        }
+    /// URI de metadados de um emblema, com `{id}` substituído pelo número de série
+    pub fn uri_emblema(env: Env, serial: u32) -> String {
+        emblemas::uri_emblema(env, serial)
+    }
 
-        env.storage().persistent().set(&key_recompensa, &true);
+    /// Registra, uma única vez, quem indicou um novo leitor (antes de sua primeira leitura).
+    /// O indicador recebe um bônus quando o indicado conclui seu primeiro livro.
+    pub fn registrar_indicador(env: Env, leitor: Address, indicador: Address) {
+        indicacoes::registrar_indicador(env, leitor, indicador)
+    }
 
+    /// Quem indicou o usuário, quem ele indicou e quantos bônus de indicação recebeu
+    pub fn obter_indicacoes(env: Env, usuario: Address) -> ResumoIndicacoes {
+        indicacoes::obter_indicacoes(env, usuario)
+    }
 
-        let recompensa_em_tokens: u128 = 100_0000000;
+    /// (Admin) Define o valor do bônus de indicação e o máximo de bônus por indicador
+    pub fn configurar_indicacao(env: Env, bonus: u128, max_bonus_por_indicador: u32) {
+        exigir_admin(&env);
+        indicacoes::configurar_indicacao(env, bonus, max_bonus_por_indicador)
+    }
 
+    pub fn obter_config_indicacao(env: Env) -> Option<ConfigIndicacao> {
+        indicacoes::obter_config_indicacao(env)
+    }
 
-        RecompensaReivindicada::publish(
-            &RecompensaReivindicada{
-                leitor: leitor,
-                livro_id: livro_id,
-                valor: recompensa_em_tokens
-            }, &env
-        );
+    /// Emite o evento de recompensa ao concluir todos os livros registrados de um testamento
+    pub fn reivindicar_testamento(env: Env, leitor: Address, testamento: Testamento) {
+        recompensas::reivindicar_testamento(env, leitor, testamento)
+    }
+
+    /// Emite o evento de recompensa ao concluir todos os livros registrados
+    pub fn reivindicar_biblia(env: Env, leitor: Address) {
+        recompensas::reivindicar_biblia(env, leitor)
+    }
+}
+
+/// Exige a autorização do administrador do contrato
+fn exigir_admin(env: &Env) {
+    let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    admin.require_auth();
+}
+
+/// Garante que a tradução informada foi registrada pelo admin
+pub(crate) fn exigir_traducao(env: &Env, traducao: &Symbol) {
+    if !env.storage().persistent().has(&DataKey::Traducao(traducao.clone())) {
+        panic!("Tradução não registrada");
+    }
+}
+
+/// Compara o SHA-256 do texto com o hash oficial registrado para o texto na tradução
+fn texto_confere(env: &Env, id_texto: IdTexto, traducao: Symbol, texto: &Bytes) -> bool {
+    let hash_calculado: BytesN<32> = env.crypto().sha256(texto).into();
+
+    let hashes: Map<(IdTexto, Symbol), BytesN<32>> = env.storage().instance()
+        .get(&DataKey::Hashes)
+        .unwrap_or_else(|| Map::new(env));
+
+    if let Some(hash_oficial) = hashes.get((id_texto, traducao)) {
+        hash_oficial.cmp(&hash_calculado) == Ordering::Equal
+    } else {
+        false
+    }
+}
+
+/// Valida a prova de posse do texto exigida pelo modo estrito
+pub(crate) fn exigir_prova_leitura(env: &Env, id_texto: &IdTexto, traducao: &Symbol, texto: Option<Bytes>) {
+    match texto {
+        Some(texto) => {
+            if !texto_confere(env, id_texto.clone(), traducao.clone(), &texto) {
+                panic!("Texto não confere com o hash registrado");
+            }
+        }
+        None => {
+            let estrito: bool = env.storage().instance().get(&DataKey::ModoEstrito).unwrap_or(false);
+            if estrito {
+                panic!("Modo estrito: o texto do versículo é obrigatório");
+            }
+        }
     }
 }
 
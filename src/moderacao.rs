@@ -0,0 +1,455 @@
+//! Módulo responsável pelas denúncias e pela moderação de conteúdo
+//!
+//! Usuários denunciam reflexões públicas ou comentários (uma vez por item),
+//! e as denúncias abertas formam uma fila que os moderadores percorrem e
+//! resolvem. Ao atingir o limite configurado de denúncias, uma reflexão
+//! passa automaticamente para `StatusReflexao::EmRevisao` e deixa de
+//! aparecer até que um moderador decida.
+//!
+//! O status das reflexões segue uma máquina de estados com transições
+//! permitidas conforme o papel de quem as pede:
+//! - Autor: oculta e reexibe a própria reflexão, ou a remove definitivamente
+//! - Moderador: coloca em revisão, remove e restaura
+//! - Sistema: coloca em revisão ao atingir o limite de denúncias
+//!
+//! Comentários podem ser ocultados (sem serem apagados) pelo autor da
+//! reflexão ou por moderadores.
+
+use soroban_sdk::{Env, Address, String, Vec};
+use crate::{
+    AlvoDenuncia, ChaveSocial, Comentario, DataKey, Denuncia, IdTexto, MotivoDenuncia, OcultacaoComentario, Reflexao,
+    RegistroStatusReflexao, StatusReflexao, MAX_LIMITE_PAGINA, MAX_MOTIVO_CHARS,
+};
+use crate::estrutura::validar_id_texto;
+
+/// Garante que o endereço é o admin ou um moderador, exigindo sua autenticação
+pub(crate) fn exigir_moderador(env: &Env, moderador: &Address) {
+    moderador.require_auth();
+
+    if !eh_moderador(env, moderador) {
+        panic!("Apenas admin ou moderadores podem moderar");
+    }
+}
+
+pub(crate) fn eh_moderador(env: &Env, usuario: &Address) -> bool {
+    let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    *usuario == admin || env.storage().persistent().has(&ChaveSocial::Moderador(usuario.clone()))
+}
+
+pub fn definir_limite_ocultacao(env: Env, limite: u32) {
+    env.storage().persistent().set(&ChaveSocial::LimiteOcultacao, &limite);
+}
+
+/// Limite de denúncias que coloca uma reflexão em revisão (0 desativa)
+pub fn obter_limite_ocultacao(env: Env) -> u32 {
+    env.storage().persistent().get(&ChaveSocial::LimiteOcultacao).unwrap_or(0)
+}
+
+/// Registra uma denúncia e retorna seu id
+///
+/// Cada usuário denuncia um mesmo item uma única vez, e ninguém pode
+/// denunciar o próprio conteúdo. Só reflexões públicas podem ser denunciadas.
+pub fn denunciar(env: Env, denunciante: Address, alvo: AlvoDenuncia, motivo: MotivoDenuncia) -> u32 {
+    denunciante.require_auth();
+
+    if autor_do_alvo(&env, &alvo) == denunciante {
+        panic!("Não é possível denunciar o próprio conteúdo");
+    }
+
+    let key_feita = ChaveSocial::DenunciaFeita(denunciante.clone(), alvo.clone());
+    if env.storage().persistent().has(&key_feita) {
+        panic!("Item já denunciado por este usuário");
+    }
+
+    let id: u32 = env.storage().persistent().get(&ChaveSocial::ContadorDenuncias).unwrap_or(0);
+    let denuncia = Denuncia {
+        id,
+        denunciante,
+        alvo: alvo.clone(),
+        motivo,
+        timestamp: env.ledger().timestamp(),
+        aberta: true,
+        procedente: false,
+        resolvida_por: None,
+    };
+
+    env.storage().persistent().set(&ChaveSocial::Denuncia(id), &denuncia);
+    env.storage().persistent().set(&ChaveSocial::ContadorDenuncias, &(id + 1));
+    env.storage().persistent().set(&key_feita, &true);
+
+    let mut abertas = denuncias_abertas(&env);
+    abertas.push_back(id);
+    env.storage().persistent().set(&ChaveSocial::DenunciasAbertas, &abertas);
+
+    let key_alvo = ChaveSocial::DenunciasAlvo(alvo.clone());
+    let mut do_alvo: Vec<u32> = env.storage().persistent().get(&key_alvo).unwrap_or_else(|| Vec::new(&env));
+    do_alvo.push_back(id);
+    env.storage().persistent().set(&key_alvo, &do_alvo);
+
+    if let AlvoDenuncia::Reflexao(id_texto, autor) = alvo {
+        let limite = obter_limite_ocultacao(env.clone());
+        let status = status_reflexao(&env, &id_texto, &autor);
+
+        // Só as denúncias ainda abertas contam: as já resolvidas por um moderador não
+        // devem devolver uma reflexão restaurada à revisão
+        let mut abertas_do_alvo = 0;
+        for id in do_alvo.iter() {
+            if obter_denuncia(env.clone(), id).is_some_and(|denuncia| denuncia.aberta) {
+                abertas_do_alvo += 1;
+            }
+        }
+
+        if limite > 0 && abertas_do_alvo >= limite && status_visivel(&status) {
+            registrar_status(
+                &env,
+                &id_texto,
+                &autor,
+                StatusReflexao::EmRevisao,
+                &env.current_contract_address(),
+                String::from_str(&env, "Limite de denúncias atingido"),
+            );
+        }
+    }
+
+    id
+}
+
+pub fn obter_denuncia(env: Env, denuncia_id: u32) -> Option<Denuncia> {
+    env.storage().persistent().get(&ChaveSocial::Denuncia(denuncia_id))
+}
+
+/// Fila de denúncias abertas, da mais antiga para a mais recente
+///
+/// O `limite` é restrito a `MAX_LIMITE_PAGINA`.
+pub fn listar_denuncias_abertas(env: Env, limite: u32, offset: u32) -> Vec<Denuncia> {
+    let abertas = denuncias_abertas(&env);
+    let mut denuncias = Vec::new(&env);
+    let fim = offset.saturating_add(limite.min(MAX_LIMITE_PAGINA)).min(abertas.len());
+
+    for i in offset..fim {
+        if let Some(denuncia) = obter_denuncia(env.clone(), abertas.get(i).unwrap()) {
+            denuncias.push_back(denuncia);
+        }
+    }
+
+    denuncias
+}
+
+/// Resolve todas as denúncias abertas do item da denúncia informada
+///
+/// Procedente: a reflexão vai para `RemovidaPorModerador` ou o comentário é ocultado pelo moderador.
+/// Improcedente: uma reflexão em revisão vai para `Restaurada`.
+pub fn resolver_denuncia(env: Env, moderador: Address, denuncia_id: u32, procedente: bool) {
+    exigir_moderador(&env, &moderador);
+
+    let denuncia = obter_denuncia(env.clone(), denuncia_id).expect("Denúncia não encontrada");
+    if !denuncia.aberta {
+        panic!("Denúncia já resolvida");
+    }
+
+    let alvo = denuncia.alvo;
+    let do_alvo: Vec<u32> = env.storage()
+        .persistent()
+        .get(&ChaveSocial::DenunciasAlvo(alvo.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let mut abertas = denuncias_abertas(&env);
+    for id in do_alvo.iter() {
+        let key = ChaveSocial::Denuncia(id);
+        let mut item: Denuncia = env.storage().persistent().get(&key).unwrap();
+        if !item.aberta {
+            continue;
+        }
+
+        item.aberta = false;
+        item.procedente = procedente;
+        item.resolvida_por = Some(moderador.clone());
+        env.storage().persistent().set(&key, &item);
+
+        if let Some(indice) = abertas.first_index_of(id) {
+            abertas.remove(indice);
+        }
+    }
+    env.storage().persistent().set(&ChaveSocial::DenunciasAbertas, &abertas);
+
+    match alvo {
+        AlvoDenuncia::Reflexao(id_texto, autor) => {
+            let status = status_reflexao(&env, &id_texto, &autor);
+            let novo_status = if procedente {
+                StatusReflexao::RemovidaPorModerador
+            } else {
+                StatusReflexao::Restaurada
+            };
+
+            // Mesmas regras de `alterar_status_reflexao`: a remoção pelo autor é definitiva
+            let motivo = if procedente { "Denúncia procedente" } else { "Denúncia improcedente" };
+            if transicao_do_moderador(&status, &novo_status) && (procedente || status == StatusReflexao::EmRevisao) {
+                let novo_status = if procedente { novo_status } else { status_ao_restaurar(&env, &id_texto, &autor) };
+                registrar_status(&env, &id_texto, &autor, novo_status, &moderador, String::from_str(&env, motivo));
+            }
+        }
+        AlvoDenuncia::Comentario(id_texto, autor, comentario_id) => {
+            // O comentário pode ter sido apagado pelo próprio autor depois da denúncia
+            if procedente && buscar_comentario(&env, &id_texto, &autor, comentario_id).is_some() {
+                alterar_ocultacao(&env, &id_texto, &autor, comentario_id, OcultacaoComentario::PorModerador, &moderador);
+            }
+        }
+    }
+}
+
+/// Oculta um comentário de uma reflexão sem apagá-lo
+///
+/// Permitido ao autor da reflexão e aos moderadores; o motivo registrado
+/// indica qual dos dois papéis ocultou o comentário. Uma ocultação feita
+/// por moderador não pode ser substituída pela do autor.
+pub fn ocultar_comentario(
+    env: Env,
+    ator: Address,
+    id_texto: IdTexto,
+    autor_reflexao: Address,
+    comentario_id: u32,
+) {
+    ator.require_auth();
+    validar_id_texto(&env, &id_texto);
+
+    let ocultacao = if eh_moderador(&env, &ator) {
+        OcultacaoComentario::PorModerador
+    } else if ator == autor_reflexao {
+        // O autor não pode sobrescrever (e depois desfazer) uma ocultação do moderador
+        let comentario = obter_comentario(&env, &id_texto, &autor_reflexao, comentario_id);
+        if comentario.ocultacao == OcultacaoComentario::PorModerador {
+            panic!("Comentário já ocultado por um moderador");
+        }
+        OcultacaoComentario::PeloAutorDaReflexao
+    } else {
+        panic!("Apenas o autor da reflexão ou moderadores podem ocultar comentários");
+    };
+
+    alterar_ocultacao(&env, &id_texto, &autor_reflexao, comentario_id, ocultacao, &ator);
+}
+
+/// Torna a exibir um comentário oculto
+///
+/// Moderadores reexibem qualquer comentário; o autor da reflexão, apenas
+/// os que ele mesmo ocultou.
+pub fn reexibir_comentario(
+    env: Env,
+    ator: Address,
+    id_texto: IdTexto,
+    autor_reflexao: Address,
+    comentario_id: u32,
+) {
+    ator.require_auth();
+    validar_id_texto(&env, &id_texto);
+
+    let comentario = obter_comentario(&env, &id_texto, &autor_reflexao, comentario_id);
+    let pelo_autor = ator == autor_reflexao && comentario.ocultacao == OcultacaoComentario::PeloAutorDaReflexao;
+    if !pelo_autor && !eh_moderador(&env, &ator) {
+        panic!("Sem permissão para reexibir o comentário");
+    }
+
+    alterar_ocultacao(&env, &id_texto, &autor_reflexao, comentario_id, OcultacaoComentario::Visivel, &ator);
+}
+
+fn obter_comentario(env: &Env, id_texto: &IdTexto, autor_reflexao: &Address, comentario_id: u32) -> Comentario {
+    buscar_comentario(env, id_texto, autor_reflexao, comentario_id).expect("Comentário não encontrado")
+}
+
+fn buscar_comentario(env: &Env, id_texto: &IdTexto, autor_reflexao: &Address, comentario_id: u32) -> Option<Comentario> {
+    let comentarios: Vec<Comentario> = env.storage()
+        .persistent()
+        .get(&DataKey::ComentariosReflexao(id_texto.clone(), autor_reflexao.clone()))
+        .unwrap_or_else(|| Vec::new(env));
+
+    comentarios.iter().find(|c| c.id == comentario_id)
+}
+
+fn alterar_ocultacao(
+    env: &Env,
+    id_texto: &IdTexto,
+    autor_reflexao: &Address,
+    comentario_id: u32,
+    ocultacao: OcultacaoComentario,
+    ator: &Address,
+) {
+    let key_comentarios = DataKey::ComentariosReflexao(id_texto.clone(), autor_reflexao.clone());
+    let mut comentarios: Vec<Comentario> = env.storage()
+        .persistent()
+        .get(&key_comentarios)
+        .unwrap_or_else(|| Vec::new(env));
+
+    let indice = comentarios
+        .iter()
+        .position(|c| c.id == comentario_id)
+        .expect("Comentário não encontrado") as u32;
+
+    let mut comentario = comentarios.get(indice).unwrap();
+    comentario.ocultado_por = match ocultacao {
+        OcultacaoComentario::Visivel => None,
+        _ => Some(ator.clone()),
+    };
+    comentario.ocultacao = ocultacao;
+
+    comentarios.set(indice, comentario);
+    env.storage().persistent().set(&key_comentarios, &comentarios);
+}
+
+/// Altera o status de uma reflexão, conferindo se a transição é permitida ao papel do ator
+pub fn alterar_status_reflexao(
+    env: Env,
+    ator: Address,
+    id_texto: IdTexto,
+    autor_reflexao: Address,
+    novo_status: StatusReflexao,
+    motivo: String,
+) {
+    ator.require_auth();
+    validar_id_texto(&env, &id_texto);
+
+    if motivo.len() > MAX_MOTIVO_CHARS {
+        panic!("Motivo muito longo");
+    }
+
+    if !env.storage().persistent().has(&DataKey::Reflexoes(id_texto.clone(), autor_reflexao.clone())) {
+        panic!("Reflexão não encontrada");
+    }
+
+    let atual = status_reflexao(&env, &id_texto, &autor_reflexao);
+    let pelo_autor = ator == autor_reflexao && transicao_do_autor(&atual, &novo_status);
+    let pelo_moderador = eh_moderador(&env, &ator) && transicao_do_moderador(&atual, &novo_status);
+
+    if !pelo_autor && !pelo_moderador {
+        panic!("Transição de status não permitida");
+    }
+
+    let novo_status = if novo_status == StatusReflexao::Restaurada {
+        status_ao_restaurar(&env, &id_texto, &autor_reflexao)
+    } else {
+        novo_status
+    };
+    registrar_status(&env, &id_texto, &autor_reflexao, novo_status, &ator, motivo);
+}
+
+/// Todas as transições de status da reflexão, da criação até a mais recente
+pub fn historico_status_reflexao(env: Env, id_texto: IdTexto, autor_reflexao: Address) -> Vec<RegistroStatusReflexao> {
+    env.storage()
+        .persistent()
+        .get(&ChaveSocial::HistoricoStatus(id_texto, autor_reflexao))
+        .unwrap_or_else(|| Vec::new(&env))
+}
+
+/// Grava o novo status da reflexão e acrescenta a transição ao histórico
+pub(crate) fn registrar_status(
+    env: &Env,
+    id_texto: &IdTexto,
+    autor: &Address,
+    status: StatusReflexao,
+    ator: &Address,
+    motivo: String,
+) {
+    let registro = RegistroStatusReflexao {
+        status,
+        ator: ator.clone(),
+        timestamp: env.ledger().timestamp(),
+        motivo,
+    };
+
+    env.storage().persistent().set(&DataKey::StatusReflexoes(id_texto.clone(), autor.clone()), &registro);
+
+    let key_historico = ChaveSocial::HistoricoStatus(id_texto.clone(), autor.clone());
+    let mut historico: Vec<RegistroStatusReflexao> = env.storage()
+        .persistent()
+        .get(&key_historico)
+        .unwrap_or_else(|| Vec::new(env));
+    historico.push_back(registro);
+    env.storage().persistent().set(&key_historico, &historico);
+}
+
+pub(crate) fn status_reflexao(env: &Env, id_texto: &IdTexto, autor: &Address) -> StatusReflexao {
+    env.storage()
+        .persistent()
+        .get::<DataKey, RegistroStatusReflexao>(&DataKey::StatusReflexoes(id_texto.clone(), autor.clone()))
+        .map(|registro| registro.status)
+        .unwrap_or(StatusReflexao::Ativa)
+}
+
+pub(crate) fn status_visivel(status: &StatusReflexao) -> bool {
+    matches!(status, StatusReflexao::Ativa | StatusReflexao::Restaurada)
+}
+
+/// Impede curtidas e comentários em reflexões que não estão visíveis
+pub(crate) fn exigir_reflexao_visivel(env: &Env, id_texto: &IdTexto, autor: &Address) {
+    match status_reflexao(env, id_texto, autor) {
+        StatusReflexao::Ativa | StatusReflexao::Restaurada => {}
+        StatusReflexao::EmRevisao => panic!("Reflexão em revisão"),
+        StatusReflexao::Oculta => panic!("Reflexão oculta pelo autor"),
+        StatusReflexao::Removida | StatusReflexao::RemovidaPorModerador => panic!("Reflexão foi removida"),
+    }
+}
+
+fn transicao_do_autor(atual: &StatusReflexao, novo: &StatusReflexao) -> bool {
+    matches!(
+        (atual, novo),
+        (StatusReflexao::Ativa | StatusReflexao::Restaurada, StatusReflexao::Oculta)
+            | (StatusReflexao::Oculta, StatusReflexao::Ativa)
+            | (StatusReflexao::Ativa | StatusReflexao::Restaurada | StatusReflexao::Oculta, StatusReflexao::Removida)
+    )
+}
+
+fn transicao_do_moderador(atual: &StatusReflexao, novo: &StatusReflexao) -> bool {
+    matches!(
+        (atual, novo),
+        (StatusReflexao::Ativa | StatusReflexao::Restaurada | StatusReflexao::Oculta, StatusReflexao::EmRevisao)
+            | (
+                StatusReflexao::Ativa | StatusReflexao::Restaurada | StatusReflexao::Oculta | StatusReflexao::EmRevisao,
+                StatusReflexao::RemovidaPorModerador
+            )
+            | (StatusReflexao::EmRevisao | StatusReflexao::RemovidaPorModerador, StatusReflexao::Restaurada)
+    )
+}
+
+/// Status de uma reflexão restaurada por um moderador
+///
+/// Volta ao estado anterior à revisão ou remoção: se o autor a tinha
+/// ocultado, ela continua `Oculta`; caso contrário, fica `Restaurada`.
+fn status_ao_restaurar(env: &Env, id_texto: &IdTexto, autor: &Address) -> StatusReflexao {
+    let historico = historico_status_reflexao(env.clone(), id_texto.clone(), autor.clone());
+    for registro in historico.iter().rev() {
+        match registro.status {
+            StatusReflexao::EmRevisao | StatusReflexao::RemovidaPorModerador => continue,
+            StatusReflexao::Oculta => return StatusReflexao::Oculta,
+            _ => return StatusReflexao::Restaurada,
+        }
+    }
+
+    StatusReflexao::Restaurada
+}
+
+fn denuncias_abertas(env: &Env) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&ChaveSocial::DenunciasAbertas)
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Autor do conteúdo denunciado; falha se o item não existir ou não for público
+fn autor_do_alvo(env: &Env, alvo: &AlvoDenuncia) -> Address {
+    match alvo {
+        AlvoDenuncia::Reflexao(id_texto, autor) => {
+            validar_id_texto(env, id_texto);
+            let reflexao: Reflexao = env.storage()
+                .persistent()
+                .get(&DataKey::Reflexoes(id_texto.clone(), autor.clone()))
+                .expect("Reflexão não encontrada");
+
+            if !reflexao.publica {
+                panic!("Reflexão não é pública");
+            }
+            reflexao.leitor
+        }
+        AlvoDenuncia::Comentario(id_texto, autor, comentario_id) => {
+            validar_id_texto(env, id_texto);
+            obter_comentario(env, id_texto, autor, *comentario_id).autor
+        }
+    }
+}
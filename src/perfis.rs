@@ -0,0 +1,120 @@
+//! Módulo responsável pelos perfis públicos dos usuários
+//!
+//! Cada endereço pode ter um perfil com apelido único, bio, hash do avatar,
+//! idioma e tradução preferidos, para que as listagens mostrem nomes
+//! legíveis em vez de endereços.
+
+use soroban_sdk::{Env, Address, BytesN, String, Symbol, Vec};
+use crate::{
+    ChaveSocial, IdTexto, OrdemReflexoes, PaginaReflexoesComPerfil, Perfil, ReflexaoComPerfil, exigir_traducao,
+    MAX_APELIDO_CHARS, MIN_APELIDO_CHARS, MAX_BIO_CHARS, MAX_IDIOMA_CHARS,
+};
+use crate::reflexoes::listar_reflexoes_publicas;
+
+/// Cria ou atualiza o perfil do usuário
+///
+/// O apelido deve ser único e conter apenas letras minúsculas, dígitos ou `_`.
+/// Ao trocar de apelido, o anterior fica livre para outros usuários.
+pub fn definir_perfil(
+    env: Env,
+    usuario: Address,
+    apelido: String,
+    bio: String,
+    hash_avatar: Option<BytesN<32>>,
+    idioma: String,
+    traducao_preferida: Option<Symbol>,
+) {
+    usuario.require_auth();
+
+    validar_apelido(&apelido);
+
+    if bio.len() > MAX_BIO_CHARS {
+        panic!("Bio muito longa");
+    }
+
+    if idioma.len() > MAX_IDIOMA_CHARS {
+        panic!("Idioma inválido");
+    }
+
+    if let Some(traducao) = &traducao_preferida {
+        exigir_traducao(&env, traducao);
+    }
+
+    let key_apelido = ChaveSocial::Apelido(apelido.clone());
+    if let Some(dono) = env.storage().persistent().get::<ChaveSocial, Address>(&key_apelido) {
+        if dono != usuario {
+            panic!("Apelido já em uso");
+        }
+    }
+
+    let key_perfil = ChaveSocial::Perfil(usuario.clone());
+    if let Some(anterior) = env.storage().persistent().get::<ChaveSocial, Perfil>(&key_perfil) {
+        if anterior.apelido != apelido {
+            env.storage().persistent().remove(&ChaveSocial::Apelido(anterior.apelido));
+        }
+    }
+
+    let perfil = Perfil {
+        apelido,
+        bio,
+        hash_avatar,
+        idioma,
+        traducao_preferida,
+        atualizado_em: env.ledger().timestamp(),
+    };
+
+    env.storage().persistent().set(&key_apelido, &usuario);
+    env.storage().persistent().set(&key_perfil, &perfil);
+}
+
+pub fn obter_perfil(env: Env, usuario: Address) -> Option<Perfil> {
+    env.storage().persistent().get(&ChaveSocial::Perfil(usuario))
+}
+
+pub fn obter_perfil_por_apelido(env: Env, apelido: String) -> Option<(Address, Perfil)> {
+    let usuario: Address = env.storage().persistent().get(&ChaveSocial::Apelido(apelido))?;
+    let perfil = obter_perfil(env, usuario.clone())?;
+    Some((usuario, perfil))
+}
+
+/// Mesma listagem de `listar_reflexoes_publicas`, com apelido e avatar de cada autor
+pub fn listar_reflexoes_com_perfil(
+    env: Env,
+    id_texto: IdTexto,
+    ordem: OrdemReflexoes,
+    limite: u32,
+    cursor: Option<u32>,
+) -> PaginaReflexoesComPerfil {
+    let pagina = listar_reflexoes_publicas(env.clone(), id_texto, ordem, limite, cursor);
+
+    let mut itens = Vec::new(&env);
+    for reflexao in pagina.itens.iter() {
+        let (apelido, hash_avatar) = match obter_perfil(env.clone(), reflexao.leitor.clone()) {
+            Some(perfil) => (Some(perfil.apelido), perfil.hash_avatar),
+            None => (None, None),
+        };
+        itens.push_back(ReflexaoComPerfil { reflexao, apelido, hash_avatar });
+    }
+
+    PaginaReflexoesComPerfil {
+        itens,
+        proximo_cursor: pagina.proximo_cursor,
+        tem_mais: pagina.tem_mais,
+    }
+}
+
+fn validar_apelido(apelido: &String) {
+    let tamanho = apelido.len();
+    if !(MIN_APELIDO_CHARS..=MAX_APELIDO_CHARS).contains(&tamanho) {
+        panic!("Apelido deve ter entre 3 e 32 caracteres");
+    }
+
+    let mut buffer = [0u8; MAX_APELIDO_CHARS as usize];
+    let bytes = &mut buffer[..tamanho as usize];
+    apelido.copy_into_slice(bytes);
+
+    let valido = bytes.iter().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || *c == b'_');
+    if !valido {
+        panic!("Apelido deve conter apenas letras minúsculas, dígitos ou _");
+    }
+}
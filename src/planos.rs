@@ -0,0 +1,272 @@
+//! Módulo responsável pelos planos de leitura
+//!
+//! Admins e curadores publicam planos (ex: "Bíblia em um ano") como uma
+//! lista ordenada de tarefas diárias, cada uma com um ou mais intervalos
+//! de versículos. Leitores podem:
+//! - Se inscrever em um plano a partir de uma data
+//! - Consultar a tarefa do dia e o progresso no plano
+//! - Concluir dias, comprovados pelas leituras já registradas do leitor
+//! - Reivindicar a recompensa ao concluir todos os dias
+
+use soroban_sdk::{contractevent, Env, Address, String, Vec};
+use crate::{DataKey, InscricaoPlano, IntervaloLeitura, PlanoLeitura, ProgressoPlano, TarefaDia};
+use crate::{MAX_NOME_PLANO_CHARS, SEGUNDOS_POR_DIA};
+use crate::estrutura::{para_cada_versiculo, validar_intervalo};
+use crate::leituras::buscar_leitura;
+use crate::recompensas::consumir_orcamento;
+
+#[contractevent]
+struct RecompensaPlanoReivindicada {
+    leitor: Address,
+    plano_id: u32,
+    valor: u128,
+}
+
+/// Cria um plano vazio com nome e duração em dias
+///
+/// As tarefas diárias são adicionadas depois com `adicionar_dias_plano`,
+/// permitindo publicar planos longos em várias transações.
+pub fn criar_plano(env: Env, criador: Address, nome: String, duracao_dias: u32) -> u32 {
+    exigir_admin_ou_curador(&env, &criador);
+
+    if nome.is_empty() || nome.len() > MAX_NOME_PLANO_CHARS {
+        panic!("Nome do plano inválido");
+    }
+
+    if duracao_dias == 0 {
+        panic!("Plano precisa ter ao menos um dia");
+    }
+
+    let id: u32 = env.storage().instance().get(&DataKey::ContadorPlanos).unwrap_or(0);
+
+    let plano = PlanoLeitura {
+        id,
+        nome,
+        criador,
+        duracao_dias,
+        dias_definidos: 0,
+    };
+
+    env.storage().persistent().set(&DataKey::Plano(id), &plano);
+    env.storage().instance().set(&DataKey::ContadorPlanos, &(id + 1));
+
+    id
+}
+
+/// Acrescenta, em ordem, as tarefas dos próximos dias do plano
+///
+/// Apenas o criador do plano pode completá-lo. Cada intervalo é validado
+/// contra a estrutura canônica dos livros.
+pub fn adicionar_dias_plano(env: Env, criador: Address, plano_id: u32, dias: Vec<Vec<IntervaloLeitura>>) {
+    criador.require_auth();
+
+    let mut plano = obter_plano_existente(&env, plano_id);
+    if plano.criador != criador {
+        panic!("Apenas o criador pode editar o plano");
+    }
+
+    if plano.dias_definidos + dias.len() > plano.duracao_dias {
+        panic!("Quantidade de dias excede a duração do plano");
+    }
+
+    for intervalos in dias.iter() {
+        if intervalos.is_empty() {
+            panic!("Dia do plano precisa ter ao menos um intervalo");
+        }
+
+        for intervalo in intervalos.iter() {
+            validar_intervalo(&env, &intervalo.inicio, &intervalo.fim);
+        }
+
+        env.storage().persistent().set(&DataKey::DiaPlano(plano_id, plano.dias_definidos), &intervalos);
+        plano.dias_definidos += 1;
+    }
+
+    env.storage().persistent().set(&DataKey::Plano(plano_id), &plano);
+}
+
+pub fn obter_plano(env: Env, plano_id: u32) -> Option<PlanoLeitura> {
+    env.storage().persistent().get(&DataKey::Plano(plano_id))
+}
+
+/// Inscreve o leitor em um plano completo, começando no dia de `inicio` (timestamp)
+///
+/// O início não pode ser anterior ao dia atual, para que leituras antigas
+/// não concluam o plano de imediato.
+pub fn inscrever_plano(env: Env, leitor: Address, plano_id: u32, inicio: u64) {
+    leitor.require_auth();
+
+    let dia_inicio = inicio / SEGUNDOS_POR_DIA;
+    if dia_inicio < env.ledger().timestamp() / SEGUNDOS_POR_DIA {
+        panic!("Início do plano não pode estar no passado");
+    }
+
+    let plano = obter_plano_existente(&env, plano_id);
+    if plano.dias_definidos < plano.duracao_dias {
+        panic!("Plano ainda não foi totalmente publicado");
+    }
+
+    let key = DataKey::InscricaoPlano(leitor, plano_id);
+    if env.storage().persistent().has(&key) {
+        panic!("Leitor já inscrito neste plano");
+    }
+
+    let inscricao = InscricaoPlano {
+        plano_id,
+        dia_inicio,
+        dias_concluidos: 0,
+        dias_no_prazo: 0,
+        recompensa_recebida: false,
+    };
+    env.storage().persistent().set(&key, &inscricao);
+}
+
+/// Retorna a tarefa de hoje para o leitor, ou None se o plano não começou ou já terminou
+pub fn tarefa_do_dia(env: Env, leitor: Address, plano_id: u32) -> Option<TarefaDia> {
+    let plano = obter_plano_existente(&env, plano_id);
+    let inscricao = obter_inscricao(&env, &leitor, plano_id);
+
+    let hoje = env.ledger().timestamp() / SEGUNDOS_POR_DIA;
+    if hoje < inscricao.dia_inicio || hoje - inscricao.dia_inicio >= plano.duracao_dias as u64 {
+        return None;
+    }
+
+    let dia = (hoje - inscricao.dia_inicio) as u32;
+    let intervalos: Vec<IntervaloLeitura> = env.storage()
+        .persistent()
+        .get(&DataKey::DiaPlano(plano_id, dia))
+        .unwrap();
+
+    Some(TarefaDia {
+        dia,
+        concluida: env.storage().persistent().has(&DataKey::DiaPlanoConcluido(leitor, plano_id, dia)),
+        intervalos,
+    })
+}
+
+/// Conclui um dia do plano, comprovando que todos os versículos da tarefa foram lidos
+///
+/// Só valem leituras feitas a partir do dia de início da inscrição. O dia
+/// conta como "no prazo" se a última leitura da tarefa aconteceu até o fim
+/// do dia correspondente no calendário do leitor.
+pub fn concluir_dia_plano(env: Env, leitor: Address, plano_id: u32, dia: u32) {
+    leitor.require_auth();
+
+    let plano = obter_plano_existente(&env, plano_id);
+    let mut inscricao = obter_inscricao(&env, &leitor, plano_id);
+
+    if dia >= plano.duracao_dias {
+        panic!("Dia fora do plano");
+    }
+
+    let key_concluido = DataKey::DiaPlanoConcluido(leitor.clone(), plano_id, dia);
+    if env.storage().persistent().has(&key_concluido) {
+        panic!("Dia já concluído");
+    }
+
+    let intervalos: Vec<IntervaloLeitura> = env.storage()
+        .persistent()
+        .get(&DataKey::DiaPlano(plano_id, dia))
+        .unwrap();
+
+    let inicio_plano = inscricao.dia_inicio * SEGUNDOS_POR_DIA;
+    let mut ultima_leitura: u64 = 0;
+    for intervalo in intervalos.iter() {
+        para_cada_versiculo(&env, &intervalo.inicio, &intervalo.fim, |id_texto| {
+            match buscar_leitura(&env, &leitor, &id_texto) {
+                Some(registro) if registro.timestamp >= inicio_plano => {
+                    ultima_leitura = ultima_leitura.max(registro.timestamp)
+                }
+                Some(_) => panic!("Leitura feita antes do início do plano"),
+                None => panic!("Tarefa do dia ainda não foi lida"),
+            }
+        });
+    }
+
+    env.storage().persistent().set(&key_concluido, &true);
+
+    let fim_do_dia = (inscricao.dia_inicio + dia as u64 + 1) * SEGUNDOS_POR_DIA;
+    inscricao.dias_concluidos += 1;
+    if ultima_leitura < fim_do_dia {
+        inscricao.dias_no_prazo += 1;
+    }
+    env.storage().persistent().set(&DataKey::InscricaoPlano(leitor, plano_id), &inscricao);
+}
+
+pub fn progresso_plano(env: Env, leitor: Address, plano_id: u32) -> ProgressoPlano {
+    let plano = obter_plano_existente(&env, plano_id);
+    let inscricao = obter_inscricao(&env, &leitor, plano_id);
+
+    let hoje = env.ledger().timestamp() / SEGUNDOS_POR_DIA;
+    let dias_decorridos = if hoje < inscricao.dia_inicio {
+        0
+    } else {
+        (hoje - inscricao.dia_inicio + 1).min(plano.duracao_dias as u64) as u32
+    };
+
+    ProgressoPlano {
+        plano_id,
+        duracao_dias: plano.duracao_dias,
+        dias_decorridos,
+        dias_concluidos: inscricao.dias_concluidos,
+        dias_no_prazo: inscricao.dias_no_prazo,
+        recompensa_recebida: inscricao.recompensa_recebida,
+    }
+}
+
+/// Reivindica a recompensa por concluir todos os dias de um plano
+///
+/// Assim como `reivindicar_recompensa_livro`, consome o orçamento da época
+/// atual e apenas emite um evento para que o backend realize o pagamento do token.
+pub fn reivindicar_recompensa_plano(env: Env, leitor: Address, plano_id: u32) {
+    leitor.require_auth();
+
+    let plano = obter_plano_existente(&env, plano_id);
+    let mut inscricao = obter_inscricao(&env, &leitor, plano_id);
+
+    if inscricao.recompensa_recebida {
+        panic!("Recompensa por este plano já foi recebida!");
+    }
+
+    if inscricao.dias_concluidos < plano.duracao_dias {
+        panic!("Plano ainda não concluído. Continue lendo!");
+    }
+
+    inscricao.recompensa_recebida = true;
+    env.storage().persistent().set(&DataKey::InscricaoPlano(leitor.clone(), plano_id), &inscricao);
+
+    let recompensa_em_tokens: u128 = 100_0000000;
+    consumir_orcamento(&env, recompensa_em_tokens);
+
+    RecompensaPlanoReivindicada::publish(
+        &RecompensaPlanoReivindicada {
+            leitor,
+            plano_id,
+            valor: recompensa_em_tokens,
+        }, &env
+    );
+}
+
+/// Exige que o endereço seja o admin ou um curador cadastrado
+fn exigir_admin_ou_curador(env: &Env, criador: &Address) {
+    criador.require_auth();
+
+    let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    if *criador != admin && !env.storage().persistent().has(&DataKey::Curador(criador.clone())) {
+        panic!("Apenas admin ou curadores podem publicar planos");
+    }
+}
+
+fn obter_plano_existente(env: &Env, plano_id: u32) -> PlanoLeitura {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Plano(plano_id))
+        .expect("Plano não encontrado")
+}
+
+fn obter_inscricao(env: &Env, leitor: &Address, plano_id: u32) -> InscricaoPlano {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InscricaoPlano(leitor.clone(), plano_id))
+        .expect("Leitor não inscrito neste plano")
+}
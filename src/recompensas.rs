@@ -0,0 +1,246 @@
+//! Módulo responsável pelas recompensas de leitura
+//!
+//! As recompensas não transferem tokens diretamente: cada reivindicação
+//! bem-sucedida emite um evento que o backend escuta para pagar o token TAL.
+//! Existem recompensas independentes, cada uma com sua marca de recebimento:
+//! - Por livro concluído
+//! - Por testamento concluído (Antigo ou Novo)
+//! - Pela Bíblia inteira
+//!
+//! Toda reivindicação respeita a pausa global e consome o orçamento da
+//! época de recompensas atual, configurada pelo admin.
+
+use soroban_sdk::{contractevent, panic_with_error, Env, Address};
+use crate::{DataKey, EpocaRecompensa, Erro, EstruturaLivro, Testamento};
+use crate::estrutura::{canon_completo, livros_do_testamento};
+use crate::leituras::progresso_sincronizado;
+use crate::emblemas::cunhar_emblema;
+use crate::indicacoes::pagar_bonus_indicacao;
+
+/// Cria uma nova época de recompensas, que passa a ser a época atual
+pub fn criar_epoca_recompensa(env: Env, inicio: u64, fim: u64, orcamento: u128) -> u32 {
+    if inicio >= fim {
+        panic!("Fim da época deve ser posterior ao início");
+    }
+
+    let id: u32 = env.storage().instance().get(&DataKey::ContadorEpocas).unwrap_or(0);
+
+    let epoca = EpocaRecompensa {
+        id,
+        inicio,
+        fim,
+        orcamento,
+        distribuido: 0,
+    };
+
+    env.storage().persistent().set(&DataKey::Epoca(id), &epoca);
+    env.storage().instance().set(&DataKey::ContadorEpocas, &(id + 1));
+
+    id
+}
+
+pub fn obter_epoca_atual(env: Env) -> Option<EpocaRecompensa> {
+    let contador: u32 = env.storage().instance().get(&DataKey::ContadorEpocas).unwrap_or(0);
+    if contador == 0 {
+        return None;
+    }
+
+    env.storage().persistent().get(&DataKey::Epoca(contador - 1))
+}
+
+/// Orçamento ainda disponível na época atual (0 se não houver época)
+pub fn orcamento_restante(env: Env) -> u128 {
+    match obter_epoca_atual(env) {
+        Some(epoca) => epoca.orcamento - epoca.distribuido,
+        None => 0,
+    }
+}
+
+/// Valida a pausa, a época e o orçamento e desconta `valor` da época atual
+///
+/// Chamada por todas as reivindicações antes de emitir o evento de pagamento.
+pub(crate) fn consumir_orcamento(env: &Env, valor: u128) {
+    let pausadas: bool = env.storage().instance().get(&DataKey::RecompensasPausadas).unwrap_or(false);
+    if pausadas {
+        panic_with_error!(env, Erro::RecompensasPausadas);
+    }
+
+    let mut epoca = match obter_epoca_atual(env.clone()) {
+        Some(epoca) => epoca,
+        None => panic_with_error!(env, Erro::SemEpocaAtiva),
+    };
+
+    let agora = env.ledger().timestamp();
+    if agora < epoca.inicio || agora >= epoca.fim {
+        panic_with_error!(env, Erro::ForaDaEpoca);
+    }
+
+    if epoca.orcamento - epoca.distribuido < valor {
+        panic_with_error!(env, Erro::OrcamentoEsgotado);
+    }
+
+    epoca.distribuido += valor;
+    env.storage().persistent().set(&DataKey::Epoca(epoca.id), &epoca);
+}
+
+#[contractevent]
+struct RecompensaReivindicada {
+    leitor: Address,
+    livro_id: u32,
+    valor: u128,
+}
+
+#[contractevent]
+struct RecompensaTestamento {
+    leitor: Address,
+    testamento: Testamento,
+    valor: u128,
+}
+
+#[contractevent]
+struct RecompensaBiblia {
+    leitor: Address,
+    valor: u128,
+}
+
+/// Recompensa por concluir um livro
+///
+/// Compara o progresso do leitor com o total de versículos da estrutura do livro.
+/// Se a estrutura perdeu versículos depois das leituras, o progresso precisa ser
+/// recontado antes (`recontar_progresso`).
+/// Cunha o emblema de conclusão, cujo número de série fica em `RecompensaRecebida`,
+/// e no primeiro livro do leitor paga o bônus de indicação ao seu indicador.
+pub fn reivindicar_recompensa_livro(env: Env, leitor: Address, livro_id: u32) {
+    leitor.require_auth();
+
+    let key_recompensa = DataKey::RecompensaRecebida(leitor.clone(), livro_id);
+    if env.storage().persistent().has(&key_recompensa) {
+        panic!("Recompensa por este livro já foi recebida!");
+    }
+
+    let estrutura: EstruturaLivro = match env.storage()
+        .persistent()
+        .get(&DataKey::EstruturaLivro(livro_id)) {
+        Some(estrutura) => estrutura,
+        None => panic_with_error!(&env, Erro::LivroNaoRegistrado),
+    };
+
+    if !progresso_sincronizado(&env, &leitor, livro_id, &estrutura) {
+        panic!("Estrutura do livro mudou: o progresso precisa ser recontado");
+    }
+
+    let lidos: u32 = env.storage()
+        .persistent()
+        .get(&DataKey::ProgressoLeitura(leitor.clone(), livro_id))
+        .unwrap_or(0);
+    if lidos < estrutura.total_versiculos {
+        panic!("Livro ainda não concluído. Continue lendo!");
+    }
+
+    let recompensa_em_tokens: u128 = 100_0000000;
+    consumir_orcamento(&env, recompensa_em_tokens);
+
+    let serial = cunhar_emblema(&env, &leitor, livro_id);
+    env.storage().persistent().set(&key_recompensa, &serial);
+    pagar_bonus_indicacao(&env, &leitor);
+
+
+    RecompensaReivindicada::publish(
+        &RecompensaReivindicada{
+            leitor,
+            livro_id,
+            valor: recompensa_em_tokens
+        }, &env
+    );
+}
+
+/// Recompensa por ler todos os versículos registrados de um testamento
+///
+/// Só é liberada com o cânon do testamento completo (`definir_livros_canon`),
+/// e cada livro do testamento precisa estar concluído pelos seus contadores.
+pub fn reivindicar_testamento(env: Env, leitor: Address, testamento: Testamento) {
+    leitor.require_auth();
+
+    let key_recompensa = DataKey::RecompensaTestamentoRecebida(leitor.clone(), testamento.clone());
+    if env.storage().persistent().has(&key_recompensa) {
+        panic!("Recompensa por este testamento já foi recebida!");
+    }
+
+    if !canon_completo(&env, &testamento) {
+        panic!("Cânon do testamento incompleto");
+    }
+
+    let livros = livros_do_testamento(&env, &testamento);
+
+    if !livros.iter().all(|livro_id| livro_concluido(&env, &leitor, livro_id)) {
+        panic!("Testamento ainda não concluído. Continue lendo!");
+    }
+
+    env.storage().persistent().set(&key_recompensa, &true);
+
+    let recompensa_em_tokens: u128 = 1000_0000000;
+    consumir_orcamento(&env, recompensa_em_tokens);
+
+    RecompensaTestamento::publish(
+        &RecompensaTestamento {
+            leitor,
+            testamento,
+            valor: recompensa_em_tokens,
+        }, &env
+    );
+}
+
+/// Recompensa por ler todos os versículos registrados do cânon
+///
+/// Exige o cânon completo nos dois testamentos e, assim como no testamento,
+/// verifica a conclusão de cada livro pelos seus contadores.
+pub fn reivindicar_biblia(env: Env, leitor: Address) {
+    leitor.require_auth();
+
+    let key_recompensa = DataKey::RecompensaBibliaRecebida(leitor.clone());
+    if env.storage().persistent().has(&key_recompensa) {
+        panic!("Recompensa pela Bíblia completa já foi recebida!");
+    }
+
+    if !canon_completo(&env, &Testamento::Antigo) || !canon_completo(&env, &Testamento::Novo) {
+        panic!("Cânon da Bíblia incompleto");
+    }
+
+    let mut livros = livros_do_testamento(&env, &Testamento::Antigo);
+    livros.append(&livros_do_testamento(&env, &Testamento::Novo));
+
+    if !livros.iter().all(|livro_id| livro_concluido(&env, &leitor, livro_id)) {
+        panic!("Bíblia ainda não concluída. Continue lendo!");
+    }
+
+    env.storage().persistent().set(&key_recompensa, &true);
+
+    let recompensa_em_tokens: u128 = 5000_0000000;
+    consumir_orcamento(&env, recompensa_em_tokens);
+
+    RecompensaBiblia::publish(
+        &RecompensaBiblia {
+            leitor,
+            valor: recompensa_em_tokens,
+        }, &env
+    );
+}
+
+/// Verifica pelos contadores se o leitor leu todos os versículos da estrutura do livro
+///
+/// Custa poucas leituras por livro, o que mantém as recompensas de testamento
+/// e da Bíblia dentro do orçamento de uma transação.
+fn livro_concluido(env: &Env, leitor: &Address, livro_id: u32) -> bool {
+    let estrutura: EstruturaLivro = match env.storage()
+        .persistent()
+        .get(&DataKey::EstruturaLivro(livro_id)) {
+        Some(estrutura) => estrutura,
+        None => return false,
+    };
+
+    let lidos: u32 = env.storage()
+        .persistent()
+        .get(&DataKey::ProgressoLeitura(leitor.clone(), livro_id))
+        .unwrap_or(0);
+    progresso_sincronizado(env, leitor, livro_id, &estrutura) && lidos >= estrutura.total_versiculos
+}
@@ -7,8 +7,12 @@
 //! - Navegar através de reflexões de outros usuários
 //! - Remover seus próprios comentários
 
-use soroban_sdk::{Env, Address, String, Vec, Map};
-use crate::{DataKey, Reflexao, Comentario, StatusReflexao, IdTexto, MAX_REFLEXAO_CHARS, MAX_COMENTARIO_CHARS};
+use soroban_sdk::{Env, Address, String, Symbol, Vec, Map};
+use crate::{ChaveSocial, DataKey, OrdemReflexoes, PaginaReflexoes, Reflexao, Comentario, OcultacaoComentario, RegistroStatusReflexao, StatusReflexao, IdTexto, MAX_LIMITE_PAGINA, MAX_REFLEXAO_CHARS, MAX_TOP_REFLEXOES, MAX_VARREDURA_PAGINA, MAX_COMENTARIO_CHARS};
+use crate::estrutura::validar_id_texto;
+use crate::tags::{indexar_reflexao, validar_tags};
+use crate::bloqueios::exigir_nao_bloqueado;
+use crate::moderacao::{eh_moderador, exigir_reflexao_visivel, registrar_status, status_reflexao, status_visivel};
 
 /// Adiciona uma nova reflexão pessoal sobre um texto bíblico
 /// 
@@ -17,47 +21,45 @@ use crate::{DataKey, Reflexao, Comentario, StatusReflexao, IdTexto, MAX_REFLEXAO
 /// 
 /// Validações:
 /// - Usuário deve estar autenticado
-/// - Texto deve existir no sistema
+/// - Texto deve existir no sistema na tradução informada
 /// - Usuário deve ter lido o texto previamente
 /// - Apenas uma reflexão por usuário por texto
 /// - Respeita limite de caracteres para otimização de custos
+/// - Tags devem pertencer ao vocabulário registrado pelo admin
 pub fn adicionar_reflexao(
     env: Env,
     leitor: Address,
     id_texto: IdTexto,
+    traducao: Symbol,
     conteudo: String,
     publica: bool,
+    tags: Vec<String>,
 ) {
-    
     leitor.require_auth();
+    validar_id_texto(&env, &id_texto);
+    validar_tags(&env, &tags);
     
     
     if conteudo.len() > MAX_REFLEXAO_CHARS {
         panic!("Reflexão muito longa");
     }
     
-    if conteudo.len() == 0 {
+    if conteudo.is_empty() {
         panic!("Reflexão não pode estar vazia");
     }
     
     
-    let hashes: Map<IdTexto, soroban_sdk::BytesN<32>> = env.storage()
+    let hashes: Map<(IdTexto, Symbol), soroban_sdk::BytesN<32>> = env.storage()
         .instance()
         .get(&DataKey::Hashes)
         .unwrap_or(Map::new(&env));
     
-    if !hashes.contains_key(id_texto.clone()) {
+    if !hashes.contains_key((id_texto.clone(), traducao.clone())) {
         panic!("Texto não registrado");
     }
     
    
-    let key_leitura = (leitor.clone(), id_texto.clone());
-    let leituras: Map<(Address, IdTexto), bool> = env.storage()
-        .instance()
-        .get(&DataKey::Leituras)
-        .unwrap_or(Map::new(&env));
-    
-    if !leituras.get(key_leitura).unwrap_or(false) {
+    if !env.storage().persistent().has(&DataKey::Leitura(leitor.clone(), id_texto.clone())) {
         panic!("Precisa ler o texto antes de refletir");
     }
     
@@ -74,20 +76,35 @@ pub fn adicionar_reflexao(
     let reflexao = Reflexao {
         leitor: leitor.clone(),
         id_texto: id_texto.clone(),
+        traducao,
         conteudo,
         timestamp: env.ledger().timestamp(),
         hash_reflexao: hash_conteudo,
         publica,
         curtidas: 0,
+        tags: tags.clone(),
     };
     
 
     env.storage().persistent().set(&key_reflexao, &reflexao);
     
-    let key_status = DataKey::StatusReflexoes(id_texto.clone(), leitor.clone());
-    env.storage().persistent().set(&key_status, &StatusReflexao::Ativa);
+    registrar_status(
+        &env,
+        &id_texto,
+        &leitor,
+        StatusReflexao::Ativa,
+        &leitor,
+        String::from_str(&env, ""),
+    );
+
+    let key_contador_autor = ChaveSocial::ContadorReflexoesAutor(leitor.clone());
+    let contador_autor: u32 = env.storage().persistent().get(&key_contador_autor).unwrap_or(0);
+    env.storage().persistent().set(&ChaveSocial::ReflexaoAutor(leitor.clone(), contador_autor), &id_texto);
+    env.storage().persistent().set(&key_contador_autor, &(contador_autor + 1));
     
     if publica {
+        indexar_reflexao(&env, &tags, &id_texto, &leitor);
+
         let mut contador: u32 = env.storage()
             .persistent()
             .get(&DataKey::ContadorReflexoes(id_texto.clone()))
@@ -111,58 +128,219 @@ pub fn obter_reflexao(
     leitor: Address,
     id_texto: IdTexto,
 ) -> Option<Reflexao> {
-    let key = DataKey::Reflexoes(id_texto.clone(), leitor.clone());
-    let key_status = DataKey::StatusReflexoes(id_texto, leitor);
+    validar_id_texto(&env, &id_texto);
     
-    let status: StatusReflexao = env.storage()
-        .persistent()
-        .get(&key_status)
-        .unwrap_or(StatusReflexao::Ativa);
-    
-    match status {
-        StatusReflexao::Removida => None,
-        StatusReflexao::Ativa => env.storage().persistent().get(&key),
+    if !status_visivel(&status_reflexao(&env, &id_texto, &leitor)) {
+        return None;
     }
+
+    env.storage().persistent().get(&DataKey::Reflexoes(id_texto, leitor))
 }
 
+/// Lista reflexões públicas e ativas de um texto na ordem pedida
+///
+/// O `cursor` é `None` na primeira página; nas seguintes, é o `proximo_cursor`
+/// da página anterior. Nas ordens cronológicas ele é o índice do registro
+/// público (`ReflexoesPublicas`) onde a próxima página começa, então
+/// reflexões novas entre uma chamada e outra não deslocam a paginação: em
+/// `Recentes` a leitura desce a partir desse índice. Registros removidos ou
+/// privados são pulados sem encurtar a página.
+///
+/// Em `MaisCurtidas`, as posições abaixo de `MAX_TOP_REFLEXOES` percorrem o
+/// ranking mantido por `curtir_reflexao`; depois dele vêm as demais
+/// reflexões públicas (sem curtidas ou fora do ranking), na ordem do
+/// registro, na posição `MAX_TOP_REFLEXOES + índice`.
 pub fn listar_reflexoes_publicas(
     env: Env,
     id_texto: IdTexto,
+    ordem: OrdemReflexoes,
+    limite: u32,
+    cursor: Option<u32>,
+) -> PaginaReflexoes {
+    validar_id_texto(&env, &id_texto);
+
+    let contador: u32 = env.storage()
+        .persistent()
+        .get(&DataKey::ContadorReflexoes(id_texto.clone()))
+        .unwrap_or(0);
+
+    let buscar = |slot: u32| {
+        let leitor: Address = env.storage()
+            .persistent()
+            .get(&DataKey::ReflexoesPublicas(id_texto.clone(), slot))?;
+        reflexao_visivel(&env, &id_texto, leitor)
+    };
+
+    match ordem {
+        OrdemReflexoes::MaisCurtidas => {
+            let ranking = obter_ranking(&env, &id_texto);
+            let inicio = cursor.unwrap_or(0);
+            let posicoes = (inicio..ranking.len())
+                .chain(inicio.max(MAX_TOP_REFLEXOES)..MAX_TOP_REFLEXOES.saturating_add(contador));
+
+            paginar(&env, posicoes, limite, |posicao| {
+                if posicao < MAX_TOP_REFLEXOES {
+                    let (autor, _) = ranking.get(posicao).unwrap();
+                    return reflexao_visivel(&env, &id_texto, autor);
+                }
+
+                // Quem está no ranking já foi listado acima
+                let reflexao = buscar(posicao - MAX_TOP_REFLEXOES)?;
+                if ranking.iter().any(|(autor, _)| autor == reflexao.leitor) {
+                    return None;
+                }
+                Some(reflexao)
+            })
+        }
+        OrdemReflexoes::Recentes => {
+            // Fim exclusivo: do índice do cursor (ou do registro mais novo) até o 0
+            let fim = match cursor {
+                Some(slot) => slot.saturating_add(1).min(contador),
+                None => contador,
+            };
+            paginar(&env, (0..fim).rev(), limite, buscar)
+        }
+        OrdemReflexoes::Antigas => paginar(&env, cursor.unwrap_or(0)..contador, limite, buscar),
+    }
+}
+
+/// Monta uma página de até `limite` reflexões visíveis percorrendo `posicoes`
+///
+/// `buscar` devolve a reflexão de uma posição, ou `None` se ela não deve
+/// aparecer. O `limite` fica entre 1 e `MAX_LIMITE_PAGINA`, para que toda
+/// página avance o cursor, e cada chamada
+/// examina no máximo `MAX_VARREDURA_PAGINA` posições; se a varredura parar
+/// antes do fim, a página pode vir incompleta, mas com `tem_mais` verdadeiro.
+/// O `proximo_cursor` é a primeira posição ainda não entregue.
+pub(crate) fn paginar<I, F>(env: &Env, posicoes: I, limite: u32, mut buscar: F) -> PaginaReflexoes
+where
+    I: Iterator<Item = u32>,
+    F: FnMut(u32) -> Option<Reflexao>,
+{
+    let limite = limite.clamp(1, MAX_LIMITE_PAGINA);
+    let mut itens = Vec::new(env);
+    let mut posicoes = posicoes.peekable();
+    let mut varridas = 0;
+
+    while let Some(&posicao) = posicoes.peek() {
+        if varridas == MAX_VARREDURA_PAGINA {
+            return PaginaReflexoes { itens, proximo_cursor: Some(posicao), tem_mais: true };
+        }
+
+        if let Some(reflexao) = buscar(posicao) {
+            if itens.len() == limite {
+                return PaginaReflexoes { itens, proximo_cursor: Some(posicao), tem_mais: true };
+            }
+            itens.push_back(reflexao);
+        }
+        posicoes.next();
+        varridas += 1;
+    }
+
+    PaginaReflexoes { itens, proximo_cursor: None, tem_mais: false }
+}
+
+/// Reflexão do autor sobre o texto, se estiver visível e pública
+pub(crate) fn reflexao_visivel(env: &Env, id_texto: &IdTexto, autor: Address) -> Option<Reflexao> {
+    if !status_visivel(&status_reflexao(env, id_texto, &autor)) {
+        return None;
+    }
+
+    let reflexao: Reflexao = env.storage()
+        .persistent()
+        .get(&DataKey::Reflexoes(id_texto.clone(), autor))?;
+
+    if reflexao.publica {
+        Some(reflexao)
+    } else {
+        None
+    }
+}
+
+fn obter_ranking(env: &Env, id_texto: &IdTexto) -> Vec<(Address, u32)> {
+    env.storage()
+        .persistent()
+        .get(&ChaveSocial::TopReflexoes(id_texto.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+/// Reposiciona o autor no ranking do texto após uma curtida ou descurtida
+///
+/// O ranking guarda no máximo `MAX_TOP_REFLEXOES` entradas (autor, curtidas),
+/// da mais curtida para a menos curtida; em caso de empate, fica à frente
+/// quem alcançou a contagem primeiro. Reflexões sem curtidas saem do ranking.
+fn atualizar_ranking(env: &Env, id_texto: &IdTexto, autor: &Address, curtidas: u32) {
+    let mut ranking = obter_ranking(env, id_texto);
+
+    if let Some(indice) = ranking.iter().position(|(a, _)| a == *autor) {
+        ranking.remove(indice as u32);
+    }
+
+    if curtidas > 0 {
+        let posicao = ranking.iter().take_while(|(_, c)| *c >= curtidas).count() as u32;
+        if posicao < MAX_TOP_REFLEXOES {
+            ranking.insert(posicao, (autor.clone(), curtidas));
+        }
+    }
+
+    while ranking.len() > MAX_TOP_REFLEXOES {
+        ranking.pop_back();
+    }
+
+    env.storage().persistent().set(&ChaveSocial::TopReflexoes(id_texto.clone()), &ranking);
+}
+
+/// Diário de reflexões de um autor, da mais antiga para a mais recente
+///
+/// Usa o índice mantido em `adicionar_reflexao`. As reflexões privadas só
+/// são incluídas quando o próprio autor se autentica; sem elas, a lista serve
+/// de feed público do autor.
+///
+/// `offset` e `limite` percorrem o índice do autor (que também contém as
+/// privadas e removidas), então uma página pode vir com menos itens que o
+/// `limite`. O `limite` é restrito a `MAX_LIMITE_PAGINA`.
+pub fn listar_reflexoes_do_autor(
+    env: Env,
+    autor: Address,
+    incluir_privadas: bool,
     limite: u32,
     offset: u32,
 ) -> Vec<Reflexao> {
+    if incluir_privadas {
+        autor.require_auth();
+    }
+
     let contador: u32 = env.storage()
         .persistent()
-        .get(&DataKey::ContadorReflexoes(id_texto.clone()))
+        .get(&ChaveSocial::ContadorReflexoesAutor(autor.clone()))
         .unwrap_or(0);
-    
+
     let mut reflexoes = Vec::new(&env);
-    let fim = (offset + limite).min(contador);
-    
+    let fim = offset.saturating_add(limite.min(MAX_LIMITE_PAGINA)).min(contador);
+
     for i in offset..fim {
-        if let Some(leitor) = env.storage()
+        if let Some(id_texto) = env.storage()
             .persistent()
-            .get::<DataKey, Address>(&DataKey::ReflexoesPublicas(id_texto.clone(), i)) {
-            
-            let key_status = DataKey::StatusReflexoes(id_texto.clone(), leitor.clone());
-            let status: StatusReflexao = env.storage()
+            .get::<ChaveSocial, IdTexto>(&ChaveSocial::ReflexaoAutor(autor.clone(), i)) {
+
+            // O próprio autor também vê as reflexões que ocultou
+            let status = status_reflexao(&env, &id_texto, &autor);
+            let exibir = status_visivel(&status) || (incluir_privadas && status == StatusReflexao::Oculta);
+            if !exibir {
+                continue;
+            }
+
+            if let Some(reflexao) = env.storage()
                 .persistent()
-                .get(&key_status)
-                .unwrap_or(StatusReflexao::Ativa);
-            
-            if let StatusReflexao::Ativa = status {
-                if let Some(reflexao) = env.storage()
-                    .persistent()
-                    .get::<DataKey, Reflexao>(&DataKey::Reflexoes(id_texto.clone(), leitor)) {
-                    
-                    if reflexao.publica {
-                        reflexoes.push_back(reflexao);
-                    }
+                .get::<DataKey, Reflexao>(&DataKey::Reflexoes(id_texto, autor.clone())) {
+
+                if reflexao.publica || incluir_privadas {
+                    reflexoes.push_back(reflexao);
                 }
             }
         }
     }
-    
+
     reflexoes
 }
 
@@ -170,7 +348,8 @@ pub fn listar_reflexoes_publicas(
 /// 
 /// Cria gamificação e permite que a comunidade destaque
 /// reflexões valiosas através de um sistema de votação simples.
-/// Funciona como toggle para permitir descurtir.
+/// Funciona como toggle para permitir descurtir, e mantém o
+/// ranking das reflexões mais curtidas de cada texto.
 pub fn curtir_reflexao(
     env: Env,
     curtidor: Address,
@@ -178,6 +357,7 @@ pub fn curtir_reflexao(
     autor_reflexao: Address,
 ) {
     curtidor.require_auth();
+    validar_id_texto(&env, &id_texto);
     
     let key_reflexao = DataKey::Reflexoes(id_texto.clone(), autor_reflexao.clone());
     let mut reflexao: Reflexao = env.storage()
@@ -185,15 +365,7 @@ pub fn curtir_reflexao(
         .get(&key_reflexao)
         .expect("Reflexão não encontrada");
     
-    let key_status = DataKey::StatusReflexoes(id_texto.clone(), autor_reflexao.clone());
-    let status: StatusReflexao = env.storage()
-        .persistent()
-        .get(&key_status)
-        .unwrap_or(StatusReflexao::Ativa);
-    
-    if let StatusReflexao::Removida = status {
-        panic!("Reflexão foi removida");
-    }
+    exigir_reflexao_visivel(&env, &id_texto, &autor_reflexao);
     
     if !reflexao.publica {
         panic!("Reflexão não é pública");
@@ -206,14 +378,17 @@ pub fn curtir_reflexao(
         .unwrap_or(false);
     
     if ja_curtiu {
+        // Descurtir continua permitido a quem foi bloqueado depois de curtir
         env.storage().persistent().remove(&key_curtida);
         reflexao.curtidas = reflexao.curtidas.saturating_sub(1);
     } else {
+        exigir_nao_bloqueado(&env, &autor_reflexao, &curtidor);
         env.storage().persistent().set(&key_curtida, &true);
         reflexao.curtidas += 1;
     }
     
     env.storage().persistent().set(&key_reflexao, &reflexao);
+    atualizar_ranking(&env, &id_texto, &autor_reflexao, reflexao.curtidas);
 }
 
 /// Sistema de comentários para discussões
@@ -229,12 +404,14 @@ pub fn comentar_reflexao(
     conteudo: String,
 ) {
     comentarista.require_auth();
+    validar_id_texto(&env, &id_texto);
+    exigir_nao_bloqueado(&env, &autor_reflexao, &comentarista);
     
     if conteudo.len() > MAX_COMENTARIO_CHARS {
         panic!("Comentário muito longo");
     }
     
-    if conteudo.len() == 0 {
+    if conteudo.is_empty() {
         panic!("Comentário não pode estar vazio");
     }
     
@@ -248,21 +425,20 @@ pub fn comentar_reflexao(
         panic!("Não é possível comentar reflexão privada");
     }
     
-    let key_status = DataKey::StatusReflexoes(id_texto.clone(), autor_reflexao.clone());
-    let status: StatusReflexao = env.storage()
-        .persistent()
-        .get(&key_status)
-        .unwrap_or(StatusReflexao::Ativa);
-    
-    if let StatusReflexao::Removida = status {
-        panic!("Reflexão foi removida");
-    }
+    exigir_reflexao_visivel(&env, &id_texto, &autor_reflexao);
     
+    let key_contador = ChaveSocial::ContadorComentarios(id_texto.clone(), autor_reflexao.clone());
+    let id: u32 = env.storage().persistent().get(&key_contador).unwrap_or(0);
+    env.storage().persistent().set(&key_contador, &(id + 1));
+
     let comentario = Comentario {
+        id,
         autor: comentarista,
         conteudo,
         timestamp: env.ledger().timestamp(),
         curtidas: 0,
+        ocultacao: OcultacaoComentario::Visivel,
+        ocultado_por: None,
     };
     
     let key_comentarios = DataKey::ComentariosReflexao(id_texto, autor_reflexao);
@@ -275,51 +451,78 @@ pub fn comentar_reflexao(
     env.storage().persistent().set(&key_comentarios, &comentarios);
 }
 
-/// Verifica status para controle de moderação
+/// Lista os comentários de uma reflexão
 /// 
-/// Sistema simples de moderação que permite ocultar
-/// conteúdo inadequado sem perder os dados permanentemente.
+/// Comentários ocultos só aparecem quando o `solicitante` é o autor
+/// da reflexão ou um moderador, autenticado.
 pub fn obter_comentarios(
     env: Env,
     id_texto: IdTexto,
     autor_reflexao: Address,
+    solicitante: Option<Address>,
 ) -> Vec<Comentario> {
+    validar_id_texto(&env, &id_texto);
+
+    let ve_ocultos = match solicitante {
+        Some(solicitante) => {
+            solicitante.require_auth();
+            solicitante == autor_reflexao || eh_moderador(&env, &solicitante)
+        }
+        None => false,
+    };
+    
     let key_comentarios = DataKey::ComentariosReflexao(id_texto, autor_reflexao);
-    env.storage()
+    let comentarios: Vec<Comentario> = env.storage()
         .persistent()
         .get(&key_comentarios)
-        .unwrap_or(Vec::new(&env))
+        .unwrap_or(Vec::new(&env));
+
+    if ve_ocultos {
+        return comentarios;
+    }
+
+    let mut visiveis = Vec::new(&env);
+    for comentario in comentarios.iter() {
+        if comentario.ocultacao == OcultacaoComentario::Visivel {
+            visiveis.push_back(comentario);
+        }
+    }
+    visiveis
 }
 
 /// Verifica status para controle de moderação
 /// 
-/// Sistema simples de moderação que permite ocultar
-/// conteúdo inadequado sem perder os dados permanentemente.
+/// Retorna o registro completo da última transição: status, quem a fez,
+/// quando e por quê. O histórico fica em `historico_status_reflexao`.
 pub fn verificar_status_reflexao(
     env: Env,
     id_texto: IdTexto,
     autor_reflexao: Address,
-) -> StatusReflexao {
+) -> RegistroStatusReflexao {
+    validar_id_texto(&env, &id_texto);
+    
     let key_status = DataKey::StatusReflexoes(id_texto, autor_reflexao);
     env.storage()
         .persistent()
         .get(&key_status)
-        .unwrap_or(StatusReflexao::Ativa)
+        .expect("Reflexão não encontrada")
 }
 
 /// Remove um comentário específico de uma reflexão
 /// 
 /// Permite que usuários removam seus próprios comentários.
 /// Apenas o autor do comentário pode removê-lo.
-/// Remove o comentário da lista permanentemente.
+/// O comentário é identificado pelo seu `id` estável, o mesmo usado
+/// em denúncias e na ocultação, e sai da lista permanentemente.
 pub fn remover_comentario(
     env: Env,
     usuario: Address,
     id_texto: IdTexto,
     autor_reflexao: Address,
-    indice_comentario: u32,
+    comentario_id: u32,
 ) {
     usuario.require_auth();
+    validar_id_texto(&env, &id_texto);
     
     let key_comentarios = DataKey::ComentariosReflexao(id_texto, autor_reflexao);
     let mut comentarios: Vec<Comentario> = env.storage()
@@ -327,15 +530,16 @@ pub fn remover_comentario(
         .get(&key_comentarios)
         .unwrap_or(Vec::new(&env));
     
-    if indice_comentario >= comentarios.len() {
-        panic!("Índice de comentário inválido");
-    }
+    let indice = comentarios
+        .iter()
+        .position(|c| c.id == comentario_id)
+        .expect("Comentário não encontrado") as u32;
     
-    let comentario = comentarios.get(indice_comentario).unwrap();
+    let comentario = comentarios.get(indice).unwrap();
     if comentario.autor != usuario {
         panic!("Apenas o autor pode remover o comentário");
     }
     
-    comentarios.remove(indice_comentario);
+    comentarios.remove(indice);
     env.storage().persistent().set(&key_comentarios, &comentarios);
-}
\ No newline at end of file
+}
@@ -0,0 +1,83 @@
+//! Módulo responsável pelo grafo social entre leitores
+//!
+//! Um leitor pode seguir outros para acompanhar suas reflexões públicas.
+//! A lista de quem ele segue, combinada com `listar_reflexoes_do_autor`,
+//! permite ao cliente montar um feed sem varrer todos os versículos.
+
+use soroban_sdk::{Env, Address, Vec};
+use crate::{ChaveSocial, ContagemSeguidores, MAX_LIMITE_PAGINA, MAX_SEGUINDO};
+
+pub fn seguir(env: Env, seguidor: Address, seguido: Address) {
+    seguidor.require_auth();
+
+    if seguidor == seguido {
+        panic!("Não é possível seguir a si mesmo");
+    }
+
+    let key_seguindo = ChaveSocial::Seguindo(seguidor.clone());
+    let mut seguindo: Vec<Address> = env.storage().persistent().get(&key_seguindo).unwrap_or_else(|| Vec::new(&env));
+
+    if seguindo.contains(&seguido) {
+        panic!("Já segue este leitor");
+    }
+
+    if seguindo.len() >= MAX_SEGUINDO {
+        panic!("Limite de leitores seguidos atingido");
+    }
+
+    seguindo.push_back(seguido.clone());
+    env.storage().persistent().set(&key_seguindo, &seguindo);
+
+    let key_seguidores = ChaveSocial::ContagemSeguidores(seguido);
+    let seguidores: u32 = env.storage().persistent().get(&key_seguidores).unwrap_or(0);
+    env.storage().persistent().set(&key_seguidores, &(seguidores + 1));
+}
+
+pub fn deixar_de_seguir(env: Env, seguidor: Address, seguido: Address) {
+    seguidor.require_auth();
+
+    let key_seguindo = ChaveSocial::Seguindo(seguidor);
+    let mut seguindo: Vec<Address> = env.storage().persistent().get(&key_seguindo).unwrap_or_else(|| Vec::new(&env));
+
+    let indice = seguindo.first_index_of(&seguido).expect("Não segue este leitor");
+    seguindo.remove(indice);
+    env.storage().persistent().set(&key_seguindo, &seguindo);
+
+    let key_seguidores = ChaveSocial::ContagemSeguidores(seguido);
+    let seguidores: u32 = env.storage().persistent().get(&key_seguidores).unwrap_or(0);
+    env.storage().persistent().set(&key_seguidores, &seguidores.saturating_sub(1));
+}
+
+pub fn segue(env: Env, seguidor: Address, seguido: Address) -> bool {
+    let seguindo: Vec<Address> = env.storage()
+        .persistent()
+        .get(&ChaveSocial::Seguindo(seguidor))
+        .unwrap_or_else(|| Vec::new(&env));
+    seguindo.contains(&seguido)
+}
+
+/// Lista, com paginação, os leitores seguidos, na ordem em que foram seguidos
+pub fn listar_seguindo(env: Env, leitor: Address, limite: u32, offset: u32) -> Vec<Address> {
+    let seguindo: Vec<Address> = env.storage()
+        .persistent()
+        .get(&ChaveSocial::Seguindo(leitor))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    let fim = offset.saturating_add(limite.min(MAX_LIMITE_PAGINA)).min(seguindo.len());
+    if offset >= fim {
+        return Vec::new(&env);
+    }
+    seguindo.slice(offset..fim)
+}
+
+pub fn obter_contagem_seguidores(env: Env, leitor: Address) -> ContagemSeguidores {
+    let seguindo: Vec<Address> = env.storage()
+        .persistent()
+        .get(&ChaveSocial::Seguindo(leitor.clone()))
+        .unwrap_or_else(|| Vec::new(&env));
+
+    ContagemSeguidores {
+        seguidores: env.storage().persistent().get(&ChaveSocial::ContagemSeguidores(leitor)).unwrap_or(0),
+        seguindo: seguindo.len(),
+    }
+}
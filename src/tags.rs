@@ -0,0 +1,78 @@
+//! Módulo responsável pelas tags (temas) das reflexões
+//!
+//! O admin mantém um vocabulário de tags (ex: "fé", "oração", "perdão").
+//! Cada reflexão pode carregar até `MAX_TAGS_REFLEXAO` tags desse vocabulário,
+//! e as públicas entram no índice da tag, permitindo explorar um tema em
+//! todos os livros em vez de apenas por `IdTexto`.
+
+use soroban_sdk::{Env, Address, String, Vec};
+use crate::{ChaveSocial, IdTexto, PaginaReflexoes, MAX_TAG_CHARS, MAX_TAGS_REFLEXAO, MAX_TAGS_VOCABULARIO};
+use crate::reflexoes::{paginar, reflexao_visivel};
+
+pub fn registrar_tag(env: Env, tag: String) {
+    if tag.is_empty() || tag.len() > MAX_TAG_CHARS {
+        panic!("Tag inválida");
+    }
+
+    let mut tags = listar_tags(env.clone());
+    if tags.contains(&tag) {
+        panic!("Tag já registrada");
+    }
+
+    if tags.len() >= MAX_TAGS_VOCABULARIO {
+        panic!("Vocabulário de tags cheio");
+    }
+
+    tags.push_back(tag);
+    env.storage().persistent().set(&ChaveSocial::Tags, &tags);
+}
+
+pub fn listar_tags(env: Env) -> Vec<String> {
+    env.storage().persistent().get(&ChaveSocial::Tags).unwrap_or_else(|| Vec::new(&env))
+}
+
+/// Confere se as tags de uma nova reflexão pertencem ao vocabulário, sem repetições
+pub(crate) fn validar_tags(env: &Env, tags: &Vec<String>) {
+    if tags.len() > MAX_TAGS_REFLEXAO {
+        panic!("Tags demais na reflexão");
+    }
+
+    let vocabulario = listar_tags(env.clone());
+    for (i, tag) in tags.iter().enumerate() {
+        if !vocabulario.contains(&tag) {
+            panic!("Tag não registrada");
+        }
+        if tags.first_index_of(&tag) != Some(i as u32) {
+            panic!("Tag repetida");
+        }
+    }
+}
+
+/// Adiciona uma reflexão pública ao índice de cada uma de suas tags
+pub(crate) fn indexar_reflexao(env: &Env, tags: &Vec<String>, id_texto: &IdTexto, autor: &Address) {
+    for tag in tags.iter() {
+        let key_contador = ChaveSocial::ContadorReflexoesTag(tag.clone());
+        let contador: u32 = env.storage().persistent().get(&key_contador).unwrap_or(0);
+
+        env.storage().persistent().set(
+            &ChaveSocial::ReflexaoTag(tag, contador),
+            &(id_texto.clone(), autor.clone()),
+        );
+        env.storage().persistent().set(&key_contador, &(contador + 1));
+    }
+}
+
+/// Reflexões públicas com a tag, na ordem em que foram escritas, paginadas por cursor
+pub fn listar_reflexoes_por_tag(env: Env, tag: String, limite: u32, cursor: Option<u32>) -> PaginaReflexoes {
+    let contador: u32 = env.storage()
+        .persistent()
+        .get(&ChaveSocial::ContadorReflexoesTag(tag.clone()))
+        .unwrap_or(0);
+
+    paginar(&env, cursor.unwrap_or(0)..contador, limite, |posicao| {
+        let (id_texto, autor): (IdTexto, Address) = env.storage()
+            .persistent()
+            .get(&ChaveSocial::ReflexaoTag(tag.clone(), posicao))?;
+        reflexao_visivel(&env, &id_texto, autor)
+    })
+}
@@ -1,15 +1,49 @@
 #[cfg(test)]
 mod tests {
-    use crate::{ContratoBiblia, ContratoBibliaClient, IdTexto};
+    use crate::{
+        AlvoDenuncia, OcultacaoComentario, ContratoBiblia, ContratoBibliaClient, Erro, IdTexto, IntervaloLeitura, MotivoDenuncia,
+        OrdemReflexoes, StatusReflexao, Testamento,
+    };
     use soroban_sdk::{
-        testutils::Address as _,
-        Address, Env, String
+        symbol_short, vec,
+        testutils::{Address as _, Ledger},
+        Address, Bytes, BytesN, Env, String, Symbol
     };
 
     fn id_gen_1_1() -> IdTexto {
         IdTexto { livro: 1, capitulo: 1, versiculo: 1 }
     }
 
+    fn registrar_ara(env: &Env, client: &ContratoBibliaClient) -> Symbol {
+        let ara = symbol_short!("ARA");
+        client.registrar_traducao(
+            &ara,
+            &String::from_str(env, "Almeida Revista e Atualizada"),
+            &String::from_str(env, "pt-BR"),
+            &String::from_str(env, "SBB"),
+        );
+        ara
+    }
+
+    /// Registra hashes (de textos fictícios) para todos os versículos de um capítulo
+    fn registrar_hashes_capitulo(env: &Env, client: &ContratoBibliaClient, livro: u32, capitulo: u32, versiculos: u32, traducao: &Symbol) {
+        for versiculo in 1..=versiculos {
+            let texto = Bytes::from_array(env, &[livro as u8, capitulo as u8, versiculo as u8]);
+            let hash = env.crypto().sha256(&texto).into();
+            client.registrar_hash(&IdTexto { livro, capitulo, versiculo }, traducao, &hash);
+        }
+    }
+
+    /// Abre uma época de recompensas sem limite de tempo e orçamento
+    fn abrir_epoca(client: &ContratoBibliaClient) {
+        client.criar_epoca_recompensa(&0, &u64::MAX, &u128::MAX);
+    }
+
+    /// Registra uma versão reduzida de Gênesis: capítulo 1 com 31 versículos e capítulo 2 com 25
+    fn registrar_genesis(env: &Env, client: &ContratoBibliaClient) {
+        client.registrar_estrutura_livro(&1, &Testamento::Antigo, &vec![env, 31, 25]);
+    }
+
     #[test]
     fn test_funcionalidades_basicas() {
         let env = Env::default();
@@ -29,17 +63,26 @@ mod tests {
         env.mock_all_auths();
 
 
-        client.registrar_hash(&id_texto, &hash_sha256);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+
 
+        let resultado = client.marcar_lido(&leitor, &id_texto, &ara, &None);
+        assert!(resultado.nova_leitura);
+        assert_eq!(resultado.progresso_livro, 1);
+        assert_eq!(resultado.meta_livro, Some(56));
 
-        client.marcar_lido(&leitor, &id_texto);
+        let repetida = client.marcar_lido(&leitor, &id_texto, &ara, &None);
+        assert!(!repetida.nova_leitura);
+        assert_eq!(repetida.progresso_livro, 1);
 
 
         let status_leitura = client.verificar_leitura(&leitor, &id_texto);
-        assert_eq!(status_leitura, String::from_str(&env, "Leitura confirmada!"));
+        assert!(status_leitura);
 
 
-        let texto_valido = client.verificar_texto(&id_texto, &texto.to_bytes());
+        let texto_valido = client.verificar_texto(&id_texto, &ara, &texto.to_bytes());
         assert!(texto_valido);
     }
 
@@ -59,12 +102,14 @@ mod tests {
 
         env.mock_all_auths();
         client.initialize(&admin);
-        client.registrar_hash(&id_texto, &hash_sha256);
-        client.marcar_lido(&leitor, &id_texto);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.marcar_lido(&leitor, &id_texto, &ara, &None);
 
 
         let reflexao_conteudo = String::from_str(&env, "Esta passagem me faz refletir sobre...");
-        client.adicionar_reflexao(&leitor, &id_texto, &reflexao_conteudo, &true);
+        client.adicionar_reflexao(&leitor, &id_texto, &ara, &reflexao_conteudo, &true, &vec![&env]);
 
         let reflexao = client.obter_reflexao(&leitor, &id_texto);
         assert!(reflexao.is_some());
@@ -79,7 +124,7 @@ mod tests {
         let comentario = String::from_str(&env, "Excelente reflexão!");
         client.comentar_reflexao(&curtidor, &id_texto, &leitor, &comentario);
         
-        let comentarios = client.obter_comentarios(&id_texto, &leitor);
+        let comentarios = client.obter_comentarios(&id_texto, &leitor, &None);
         assert_eq!(comentarios.len(), 1);
         assert_eq!(comentarios.get(0).unwrap().conteudo, comentario);
 
@@ -104,19 +149,21 @@ mod tests {
 
         env.mock_all_auths();
         client.initialize(&admin);
-        client.registrar_hash(&id_texto, &hash_sha256);
-        client.marcar_lido(&leitor1, &id_texto);
-        client.marcar_lido(&leitor2, &id_texto);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.marcar_lido(&leitor1, &id_texto, &ara, &None);
+        client.marcar_lido(&leitor2, &id_texto, &ara, &None);
 
 
         let reflexao1 = String::from_str(&env, "Primeira reflexão pública");
         let reflexao2 = String::from_str(&env, "Segunda reflexão pública");
         
-        client.adicionar_reflexao(&leitor1, &id_texto, &reflexao1, &true);
-        client.adicionar_reflexao(&leitor2, &id_texto, &reflexao2, &true);
+        client.adicionar_reflexao(&leitor1, &id_texto, &ara, &reflexao1, &true, &vec![&env]);
+        client.adicionar_reflexao(&leitor2, &id_texto, &ara, &reflexao2, &true, &vec![&env]);
 
 
-        let reflexoes_publicas = client.listar_reflexoes_publicas(&id_texto, &10, &0);
+        let reflexoes_publicas = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Antigas, &10, &None).itens;
         assert_eq!(reflexoes_publicas.len(), 2);
     }
 
@@ -135,15 +182,17 @@ mod tests {
   
         env.mock_all_auths();
         client.initialize(&admin);
-        client.registrar_hash(&id_texto, &hash_sha256);
-        client.marcar_lido(&leitor, &id_texto);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.marcar_lido(&leitor, &id_texto, &ara, &None);
 
    
         let reflexao_conteudo = String::from_str(&env, "Reflexão privada");
-        client.adicionar_reflexao(&leitor, &id_texto, &reflexao_conteudo, &false);
+        client.adicionar_reflexao(&leitor, &id_texto, &ara, &reflexao_conteudo, &false, &vec![&env]);
 
      
-        let reflexoes_publicas = client.listar_reflexoes_publicas(&id_texto, &10, &0);
+        let reflexoes_publicas = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Antigas, &10, &None).itens;
         assert_eq!(reflexoes_publicas.len(), 0);
 
   
@@ -167,11 +216,13 @@ mod tests {
 
         env.mock_all_auths();
         client.initialize(&admin);
-        client.registrar_hash(&id_texto, &hash_sha256);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
 
      
         let reflexao_conteudo = String::from_str(&env, "Reflexão sem leitura");
-        client.adicionar_reflexao(&leitor, &id_texto, &reflexao_conteudo, &true);
+        client.adicionar_reflexao(&leitor, &id_texto, &ara, &reflexao_conteudo, &true, &vec![&env]);
     }
 
     #[test]
@@ -190,16 +241,18 @@ mod tests {
    
         env.mock_all_auths();
         client.initialize(&admin);
-        client.registrar_hash(&id_texto, &hash_sha256);
-        client.marcar_lido(&leitor, &id_texto);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.marcar_lido(&leitor, &id_texto, &ara, &None);
 
     
         let reflexao1 = String::from_str(&env, "Primeira reflexão");
-        client.adicionar_reflexao(&leitor, &id_texto, &reflexao1, &true);
+        client.adicionar_reflexao(&leitor, &id_texto, &ara, &reflexao1, &true, &vec![&env]);
 
     
         let reflexao2 = String::from_str(&env, "Segunda reflexão");
-        client.adicionar_reflexao(&leitor, &id_texto, &reflexao2, &true);
+        client.adicionar_reflexao(&leitor, &id_texto, &ara, &reflexao2, &true, &vec![&env]);
     }
 
     #[test]
@@ -218,12 +271,14 @@ mod tests {
    
         env.mock_all_auths();
         client.initialize(&admin);
-        client.registrar_hash(&id_texto, &hash_sha256);
-        client.marcar_lido(&leitor, &id_texto);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.marcar_lido(&leitor, &id_texto, &ara, &None);
 
       
         let reflexao_longa = String::from_str(&env, "Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est laborum. Sed ut perspiciatis unde omnis iste natus error sit voluptatem accusantium doloremque laudantium, totam rem aperiam, eaque ipsa quae ab illo inventore veritatis et quasi architecto beatae vitae dicta sunt explicabo. Nemo enim ipsam voluptatem quia voluptas sit aspernatur aut odit aut fugit.");
-        client.adicionar_reflexao(&leitor, &id_texto, &reflexao_longa, &true);
+        client.adicionar_reflexao(&leitor, &id_texto, &ara, &reflexao_longa, &true, &vec![&env]);
     }
 
     #[test]
@@ -242,12 +297,14 @@ mod tests {
 
         env.mock_all_auths();
         client.initialize(&admin);
-        client.registrar_hash(&id_texto, &hash_sha256);
-        client.marcar_lido(&leitor, &id_texto);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.marcar_lido(&leitor, &id_texto, &ara, &None);
 
   
         let reflexao_conteudo = String::from_str(&env, "Reflexão sobre criação");
-        client.adicionar_reflexao(&leitor, &id_texto, &reflexao_conteudo, &true);
+        client.adicionar_reflexao(&leitor, &id_texto, &ara, &reflexao_conteudo, &true, &vec![&env]);
 
      
         let comentario1 = String::from_str(&env, "Primeiro comentário");
@@ -257,7 +314,7 @@ mod tests {
         client.comentar_reflexao(&comentarista, &id_texto, &leitor, &comentario2);
 
   
-        let comentarios = client.obter_comentarios(&id_texto, &leitor);
+        let comentarios = client.obter_comentarios(&id_texto, &leitor, &None);
         assert_eq!(comentarios.len(), 2);
         assert_eq!(comentarios.get(0).unwrap().conteudo, comentario1);
         assert_eq!(comentarios.get(1).unwrap().conteudo, comentario2);
@@ -279,26 +336,1417 @@ mod tests {
         
         env.mock_all_auths();
         client.initialize(&admin);
-        client.registrar_hash(&id_texto, &hash_sha256);
-        client.marcar_lido(&leitor, &id_texto);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.marcar_lido(&leitor, &id_texto, &ara, &None);
 
         
         let reflexao_conteudo = String::from_str(&env, "Reflexão sobre criação");
-        client.adicionar_reflexao(&leitor, &id_texto, &reflexao_conteudo, &true);
+        client.adicionar_reflexao(&leitor, &id_texto, &ara, &reflexao_conteudo, &true, &vec![&env]);
 
       
         let comentario = String::from_str(&env, "Comentário para ser removido");
         client.comentar_reflexao(&comentarista, &id_texto, &leitor, &comentario);
 
       
-        let comentarios_antes = client.obter_comentarios(&id_texto, &leitor);
+        let comentarios_antes = client.obter_comentarios(&id_texto, &leitor, &None);
         assert_eq!(comentarios_antes.len(), 1);
 
        
         client.remover_comentario(&comentarista, &id_texto, &leitor, &0);
 
 
-        let comentarios_depois = client.obter_comentarios(&id_texto, &leitor);
+        let comentarios_depois = client.obter_comentarios(&id_texto, &leitor, &None);
         assert_eq!(comentarios_depois.len(), 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_hashes_por_traducao() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+        let texto_ara = String::from_str(&env, "No princípio, criou Deus os céus e a terra.");
+        let texto_kjv = String::from_str(&env, "In the beginning God created the heaven and the earth.");
+        let hash_ara = env.crypto().sha256(&texto_ara.to_bytes()).into();
+        let hash_kjv = env.crypto().sha256(&texto_kjv.to_bytes()).into();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        let kjv = symbol_short!("KJV");
+        client.registrar_traducao(
+            &kjv,
+            &String::from_str(&env, "King James Version"),
+            &String::from_str(&env, "en"),
+            &String::from_str(&env, "Domínio público"),
+        );
+
+        assert_eq!(client.listar_traducoes().len(), 2);
+        assert_eq!(client.obter_traducao(&kjv).unwrap().idioma, String::from_str(&env, "en"));
+
+        client.registrar_hash(&id_texto, &ara, &hash_ara);
+        client.registrar_hash(&id_texto, &kjv, &hash_kjv);
+
+        assert!(client.verificar_texto(&id_texto, &ara, &texto_ara.to_bytes()));
+        assert!(client.verificar_texto(&id_texto, &kjv, &texto_kjv.to_bytes()));
+        assert!(!client.verificar_texto(&id_texto, &ara, &texto_kjv.to_bytes()));
+
+        client.marcar_lido(&leitor, &id_texto, &kjv, &None);
+        let reflexao = String::from_str(&env, "Reflexão lendo a KJV");
+        client.adicionar_reflexao(&leitor, &id_texto, &kjv, &reflexao, &true, &vec![&env]);
+        assert_eq!(client.obter_reflexao(&leitor, &id_texto).unwrap().traducao, kjv);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_traducao_nao_registrada() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let texto = String::from_str(&env, "No princípio criou Deus os céus e a terra.");
+        let hash_sha256 = env.crypto().sha256(&texto.to_bytes()).into();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        client.registrar_hash(&id_gen_1_1(), &symbol_short!("NVI"), &hash_sha256);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_registrar_hash_nao_sobrescreve() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+        let texto = String::from_str(&env, "No princípio criou Deus os céus e a terra.");
+        let errado = String::from_str(&env, "No principio criou Deus os ceus e a terra");
+        let hash_sha256 = env.crypto().sha256(&texto.to_bytes()).into();
+        let hash_errado = env.crypto().sha256(&errado.to_bytes()).into();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.registrar_hash(&id_texto, &ara, &hash_errado);
+    }
+
+    #[test]
+    fn test_corrigir_hash() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+        let errado = String::from_str(&env, "No principio criou Deus os ceus e a terra");
+        let texto = String::from_str(&env, "No princípio criou Deus os céus e a terra.");
+        let hash_errado: BytesN<32> = env.crypto().sha256(&errado.to_bytes()).into();
+        let hash_sha256: BytesN<32> = env.crypto().sha256(&texto.to_bytes()).into();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_errado);
+        assert_eq!(client.obter_revisoes_hash(&id_texto, &ara).len(), 0);
+
+        let motivo = String::from_str(&env, "Acentuação ausente");
+        client.corrigir_hash(&id_texto, &ara, &hash_sha256, &motivo);
+
+        assert!(client.verificar_texto(&id_texto, &ara, &texto.to_bytes()));
+        assert!(!client.verificar_texto(&id_texto, &ara, &errado.to_bytes()));
+
+        let revisoes = client.obter_revisoes_hash(&id_texto, &ara);
+        assert_eq!(revisoes.len(), 1);
+        let revisao = revisoes.get(0).unwrap();
+        assert_eq!(revisao.hash_anterior, hash_errado);
+        assert_eq!(revisao.hash_novo, hash_sha256);
+        assert_eq!(revisao.motivo, motivo);
+    }
+
+    #[test]
+    fn test_modo_estrito() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+        let texto = String::from_str(&env, "No princípio criou Deus os céus e a terra.");
+        let hash_sha256 = env.crypto().sha256(&texto.to_bytes()).into();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        client.registrar_hash(&id_texto, &ara, &hash_sha256);
+        client.definir_modo_estrito(&true);
+        assert!(client.modo_estrito());
+
+        assert!(client.try_marcar_lido(&leitor, &id_texto, &ara, &None).is_err());
+        let texto_falso = String::from_str(&env, "Texto qualquer");
+        assert!(client.try_marcar_lido(&leitor, &id_texto, &ara, &Some(texto_falso.to_bytes())).is_err());
+        assert!(!client.verificar_leitura(&leitor, &id_texto));
+
+        client.marcar_lido(&leitor, &id_texto, &ara, &Some(texto.to_bytes()));
+        assert!(client.verificar_leitura(&leitor, &id_texto));
+    }
+
+    #[test]
+    fn test_estrutura_livro_valida_referencias() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 2, 25, &ara);
+
+        let estrutura = client.obter_estrutura_livro(&1).unwrap();
+        assert_eq!(estrutura.total_versiculos, 56);
+
+        let livro_inexistente = IdTexto { livro: 999, capitulo: 1, versiculo: 1 };
+        assert_eq!(
+            client.try_marcar_lido(&leitor, &livro_inexistente, &ara, &None),
+            Err(Ok(Erro::LivroNaoRegistrado.into()))
+        );
+
+        let capitulo_invalido = IdTexto { livro: 1, capitulo: 3, versiculo: 1 };
+        assert_eq!(
+            client.try_marcar_lido(&leitor, &capitulo_invalido, &ara, &None),
+            Err(Ok(Erro::CapituloInvalido.into()))
+        );
+
+        let versiculo_zero = IdTexto { livro: 1, capitulo: 1, versiculo: 0 };
+        assert_eq!(
+            client.try_verificar_leitura(&leitor, &versiculo_zero),
+            Err(Ok(Erro::VersiculoInvalido.into()))
+        );
+
+        let versiculo_alem = IdTexto { livro: 1, capitulo: 2, versiculo: 26 };
+        assert_eq!(
+            client.try_obter_reflexao(&leitor, &versiculo_alem),
+            Err(Ok(Erro::VersiculoInvalido.into()))
+        );
+
+        client.marcar_lido(&leitor, &IdTexto { livro: 1, capitulo: 2, versiculo: 25 }, &ara, &None);
+    }
+
+    #[test]
+    fn test_marcar_capitulo_e_intervalo_lido() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 31, &ara);
+        registrar_hashes_capitulo(&env, &client, 1, 2, 25, &ara);
+
+        client.marcar_lido(&leitor, &IdTexto { livro: 1, capitulo: 1, versiculo: 5 }, &ara, &None);
+
+        let novas = client.marcar_capitulo_lido(&leitor, &1, &1, &ara, &None);
+        assert_eq!(novas, 30);
+
+        let inicio = IdTexto { livro: 1, capitulo: 1, versiculo: 30 };
+        let fim = IdTexto { livro: 1, capitulo: 2, versiculo: 3 };
+        let novas = client.marcar_intervalo_lido(&leitor, &inicio, &fim, &ara, &None);
+        assert_eq!(novas, 3);
+
+        assert!(client.verificar_leitura(&leitor, &IdTexto { livro: 1, capitulo: 2, versiculo: 3 }));
+        assert!(!client.verificar_leitura(&leitor, &IdTexto { livro: 1, capitulo: 2, versiculo: 4 }));
+
+        let resultado = client.marcar_lido(&leitor, &IdTexto { livro: 1, capitulo: 2, versiculo: 4 }, &ara, &None);
+        assert_eq!(resultado.progresso_livro, 35);
+
+        assert_eq!(
+            client.try_marcar_intervalo_lido(&leitor, &fim, &inicio, &ara, &None),
+            Err(Ok(Erro::IntervaloInvalido.into()))
+        );
+    }
+
+    #[test]
+    fn test_historico_leitura() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 31, &ara);
+        registrar_hashes_capitulo(&env, &client, 1, 2, 25, &ara);
+
+        env.ledger().with_mut(|ledger| {
+            ledger.timestamp = 1_000;
+            ledger.sequence_number = 10;
+        });
+        let primeiro = IdTexto { livro: 1, capitulo: 2, versiculo: 7 };
+        client.marcar_lido(&leitor, &primeiro, &ara, &None);
+
+        env.ledger().with_mut(|ledger| {
+            ledger.timestamp = 2_000;
+            ledger.sequence_number = 20;
+        });
+        let inicio = IdTexto { livro: 1, capitulo: 1, versiculo: 1 };
+        let fim = IdTexto { livro: 1, capitulo: 1, versiculo: 3 };
+        client.marcar_intervalo_lido(&leitor, &inicio, &fim, &ara, &None);
+
+        let registro = client.obter_leitura(&leitor, &primeiro).unwrap();
+        assert_eq!(registro.timestamp, 1_000);
+        assert_eq!(registro.sequencia, 10);
+        assert_eq!(registro.traducao, ara);
+
+        let historico = client.historico_leitura(&leitor, &10, &0);
+        assert_eq!(historico.len(), 4);
+        assert_eq!(historico.get(0).unwrap().id_texto, primeiro);
+        assert_eq!(historico.get(1).unwrap().id_texto, inicio);
+        assert_eq!(historico.get(3).unwrap().timestamp, 2_000);
+
+        let pagina = client.historico_leitura(&leitor, &2, &2);
+        assert_eq!(pagina.len(), 2);
+        assert_eq!(pagina.get(1).unwrap().id_texto, fim);
+    }
+
+    #[test]
+    fn test_sequencia_dias() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+        let dia = 86_400;
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 31, &ara);
+
+        for (i, versiculo) in [1, 2, 3, 4, 5, 6, 7].iter().enumerate() {
+            env.ledger().set_timestamp(100 * dia + i as u64 * dia);
+            client.marcar_lido(&leitor, &IdTexto { livro: 1, capitulo: 1, versiculo: *versiculo }, &ara, &None);
+        }
+
+        let sequencia = client.obter_sequencia(&leitor);
+        assert_eq!(sequencia.atual, 7);
+        assert_eq!(sequencia.maior, 7);
+        assert_eq!(sequencia.ultimo_dia, 106);
+
+        // Mesma data não altera a sequência
+        client.marcar_lido(&leitor, &IdTexto { livro: 1, capitulo: 1, versiculo: 8 }, &ara, &None);
+        assert_eq!(client.obter_sequencia(&leitor).atual, 7);
+
+        // Pular um dia quebra a sequência, mas mantém o recorde
+        env.ledger().set_timestamp(108 * dia);
+        assert_eq!(client.obter_sequencia(&leitor).atual, 0);
+        client.marcar_lido(&leitor, &IdTexto { livro: 1, capitulo: 1, versiculo: 9 }, &ara, &None);
+
+        let sequencia = client.obter_sequencia(&leitor);
+        assert_eq!(sequencia.atual, 1);
+        assert_eq!(sequencia.maior, 7);
+
+        // Remarcar um versículo já lido nos dias seguintes não mantém a sequência
+        for i in 1..=3 {
+            env.ledger().set_timestamp(108 * dia + i * dia);
+            client.marcar_lido(&leitor, &IdTexto { livro: 1, capitulo: 1, versiculo: 9 }, &ara, &None);
+        }
+        let sequencia = client.obter_sequencia(&leitor);
+        assert_eq!(sequencia.atual, 0);
+        assert_eq!(sequencia.ultimo_dia, 108);
+    }
+
+    #[test]
+    fn test_plano_de_leitura() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let curador = Address::generate(&env);
+        let leitor = Address::generate(&env);
+        let dia = 86_400;
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        abrir_epoca(&client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 31, &ara);
+        registrar_hashes_capitulo(&env, &client, 1, 2, 25, &ara);
+        client.adicionar_curador(&curador);
+
+        let plano_id = client.criar_plano(&curador, &String::from_str(&env, "Gênesis em dois dias"), &2);
+        let dia_1 = vec![&env, IntervaloLeitura {
+            inicio: IdTexto { livro: 1, capitulo: 1, versiculo: 1 },
+            fim: IdTexto { livro: 1, capitulo: 1, versiculo: 31 },
+        }];
+        let dia_2 = vec![&env, IntervaloLeitura {
+            inicio: IdTexto { livro: 1, capitulo: 2, versiculo: 1 },
+            fim: IdTexto { livro: 1, capitulo: 2, versiculo: 25 },
+        }];
+        client.adicionar_dias_plano(&curador, &plano_id, &vec![&env, dia_1.clone()]);
+        assert!(client.try_inscrever_plano(&leitor, &plano_id, &(10 * dia)).is_err());
+        client.adicionar_dias_plano(&curador, &plano_id, &vec![&env, dia_2]);
+
+        client.inscrever_plano(&leitor, &plano_id, &(10 * dia));
+
+        env.ledger().set_timestamp(10 * dia + 100);
+        let tarefa = client.tarefa_do_dia(&leitor, &plano_id).unwrap();
+        assert_eq!(tarefa.dia, 0);
+        assert_eq!(tarefa.intervalos, dia_1);
+        assert!(!tarefa.concluida);
+
+        assert!(client.try_concluir_dia_plano(&leitor, &plano_id, &0).is_err());
+        client.marcar_capitulo_lido(&leitor, &1, &1, &ara, &None);
+        client.concluir_dia_plano(&leitor, &plano_id, &0);
+        assert!(client.tarefa_do_dia(&leitor, &plano_id).unwrap().concluida);
+
+        // O segundo dia é lido com atraso
+        env.ledger().set_timestamp(12 * dia);
+        assert!(client.tarefa_do_dia(&leitor, &plano_id).is_none());
+        assert!(client.try_reivindicar_recompensa_plano(&leitor, &plano_id).is_err());
+        client.marcar_capitulo_lido(&leitor, &1, &2, &ara, &None);
+        client.concluir_dia_plano(&leitor, &plano_id, &1);
+
+        let progresso = client.progresso_plano(&leitor, &plano_id);
+        assert_eq!(progresso.dias_decorridos, 2);
+        assert_eq!(progresso.dias_concluidos, 2);
+        assert_eq!(progresso.dias_no_prazo, 1);
+
+        client.reivindicar_recompensa_plano(&leitor, &plano_id);
+        assert!(client.progresso_plano(&leitor, &plano_id).recompensa_recebida);
+        assert!(client.try_reivindicar_recompensa_plano(&leitor, &plano_id).is_err());
+
+        // Leituras anteriores à inscrição não concluem o plano, e o início não pode ser retroativo
+        let veterano = Address::generate(&env);
+        client.marcar_capitulo_lido(&veterano, &1, &1, &ara, &None);
+        assert!(client.try_inscrever_plano(&veterano, &plano_id, &(10 * dia)).is_err());
+        env.ledger().set_timestamp(13 * dia);
+        client.inscrever_plano(&veterano, &plano_id, &(13 * dia));
+        assert!(client.try_concluir_dia_plano(&veterano, &plano_id, &0).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_plano_exige_curador() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        client.criar_plano(&leitor, &String::from_str(&env, "Plano não autorizado"), &7);
+    }
+
+    #[test]
+    fn test_progresso_e_recompensas_por_testamento() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        // Judas (livro 65) com um único capítulo de 25 versículos
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 25]);
+        client.definir_livros_canon(&Testamento::Antigo, &1);
+        client.definir_livros_canon(&Testamento::Novo, &1);
+        let ara = registrar_ara(&env, &client);
+        abrir_epoca(&client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 31, &ara);
+        registrar_hashes_capitulo(&env, &client, 1, 2, 25, &ara);
+        registrar_hashes_capitulo(&env, &client, 65, 1, 25, &ara);
+
+        client.marcar_intervalo_lido(
+            &leitor,
+            &IdTexto { livro: 1, capitulo: 1, versiculo: 30 },
+            &IdTexto { livro: 1, capitulo: 2, versiculo: 2 },
+            &ara,
+            &None,
+        );
+        assert_eq!(client.obter_progresso_capitulo(&leitor, &1, &1), 2);
+        assert_eq!(client.obter_progresso_capitulo(&leitor, &1, &2), 2);
+        assert_eq!(client.obter_progresso_testamento(&leitor, &Testamento::Antigo), 4);
+        assert_eq!(client.obter_progresso_biblia(&leitor), 4);
+
+        assert!(client.try_reivindicar_testamento(&leitor, &Testamento::Novo).is_err());
+        client.marcar_capitulo_lido(&leitor, &65, &1, &ara, &None);
+        assert_eq!(client.obter_progresso_testamento(&leitor, &Testamento::Novo), 25);
+        client.reivindicar_testamento(&leitor, &Testamento::Novo);
+        assert!(client.try_reivindicar_testamento(&leitor, &Testamento::Novo).is_err());
+
+        assert!(client.try_reivindicar_biblia(&leitor).is_err());
+        client.marcar_capitulo_lido(&leitor, &1, &1, &ara, &None);
+        client.marcar_capitulo_lido(&leitor, &1, &2, &ara, &None);
+        assert_eq!(client.obter_progresso_biblia(&leitor), 81);
+        client.reivindicar_testamento(&leitor, &Testamento::Antigo);
+        client.reivindicar_biblia(&leitor);
+        assert!(client.try_reivindicar_biblia(&leitor).is_err());
+    }
+
+    #[test]
+    fn test_obter_progresso() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 25]);
+        let ara = registrar_ara(&env, &client);
+        abrir_epoca(&client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 31, &ara);
+        registrar_hashes_capitulo(&env, &client, 65, 1, 25, &ara);
+
+        let vazio = client.obter_progresso(&leitor, &1);
+        assert_eq!(vazio.lidos, 0);
+        assert_eq!(vazio.meta, Some(56));
+        assert_eq!(vazio.percentual_bps, 0);
+        assert_eq!(client.obter_progresso_geral(&leitor).len(), 0);
+
+        client.marcar_capitulo_lido(&leitor, &65, &1, &ara, &None);
+        client.marcar_lido(&leitor, &id_gen_1_1(), &ara, &None);
+        client.marcar_lido(&leitor, &IdTexto { livro: 1, capitulo: 1, versiculo: 2 }, &ara, &None);
+
+        let progresso = client.obter_progresso(&leitor, &1);
+        assert_eq!(progresso.lidos, 2);
+        assert_eq!(progresso.percentual_bps, 357);
+        assert!(!progresso.recompensa_recebida);
+
+        client.reivindicar_recompensa_livro(&leitor, &65);
+
+        let geral = client.obter_progresso_geral(&leitor);
+        assert_eq!(geral.len(), 2);
+        let judas = geral.get(0).unwrap();
+        assert_eq!(judas.livro_id, 65);
+        assert_eq!(judas.percentual_bps, 10_000);
+        assert!(judas.recompensa_recebida);
+        assert_eq!(geral.get(1).unwrap().livro_id, 1);
+    }
+
+    #[test]
+    fn test_progresso_exige_texto_registrado() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 25]);
+        let ara = registrar_ara(&env, &client);
+        abrir_epoca(&client);
+        registrar_hashes_capitulo(&env, &client, 65, 1, 25, &ara);
+        let kjv = symbol_short!("KJV");
+        client.registrar_traducao(
+            &kjv,
+            &String::from_str(&env, "King James Version"),
+            &String::from_str(&env, "en"),
+            &String::from_str(&env, "Domínio público"),
+        );
+
+        let sem_hash = IdTexto { livro: 65, capitulo: 1, versiculo: 1 };
+        assert_eq!(
+            client.try_marcar_lido(&leitor, &sem_hash, &kjv, &None),
+            Err(Ok(Erro::TextoNaoRegistrado.into()))
+        );
+
+        client.marcar_capitulo_lido(&leitor, &65, &1, &ara, &None);
+        assert_eq!(client.obter_progresso(&leitor, &65).lidos, 25);
+
+        // Se o livro ganha versículos, o progresso deixa de atingir a meta
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 26]);
+        assert!(client.try_reivindicar_recompensa_livro(&leitor, &65).is_err());
+
+        // Com a estrutura corrigida, a recontagem mantém apenas leituras válidas
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 20]);
+        assert_eq!(client.obter_estrutura_livro(&65).unwrap().revisao, 1);
+        assert!(client.try_reivindicar_recompensa_livro(&leitor, &65).is_err());
+        assert_eq!(client.recontar_progresso(&leitor, &65), 20);
+        assert_eq!(client.obter_progresso(&leitor, &65).lidos, 20);
+        assert_eq!(client.obter_progresso_testamento(&leitor, &Testamento::Novo), 20);
+        assert_eq!(client.obter_progresso_biblia(&leitor), 20);
+        assert_eq!(client.obter_progresso_capitulo(&leitor, &65, &1), 20);
+
+        client.reivindicar_recompensa_livro(&leitor, &65);
+    }
+
+    #[test]
+    fn test_epocas_e_pausa_de_recompensas() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor1 = Address::generate(&env);
+        let leitor2 = Address::generate(&env);
+        let leitor3 = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 25]);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 65, 1, 25, &ara);
+        for leitor in [&leitor1, &leitor2, &leitor3] {
+            client.marcar_capitulo_lido(leitor, &65, &1, &ara, &None);
+        }
+
+        assert_eq!(client.orcamento_restante(), 0);
+        assert_eq!(
+            client.try_reivindicar_recompensa_livro(&leitor1, &65),
+            Err(Ok(Erro::SemEpocaAtiva.into()))
+        );
+
+        env.ledger().set_timestamp(1_000);
+        client.criar_epoca_recompensa(&2_000, &3_000, &200_0000000);
+        assert_eq!(
+            client.try_reivindicar_recompensa_livro(&leitor1, &65),
+            Err(Ok(Erro::ForaDaEpoca.into()))
+        );
+
+        env.ledger().set_timestamp(2_500);
+        client.pausar_recompensas(&true);
+        assert_eq!(
+            client.try_reivindicar_recompensa_livro(&leitor1, &65),
+            Err(Ok(Erro::RecompensasPausadas.into()))
+        );
+        client.pausar_recompensas(&false);
+
+        client.reivindicar_recompensa_livro(&leitor1, &65);
+        client.reivindicar_recompensa_livro(&leitor2, &65);
+        assert_eq!(client.orcamento_restante(), 0);
+        assert_eq!(
+            client.try_reivindicar_recompensa_livro(&leitor3, &65),
+            Err(Ok(Erro::OrcamentoEsgotado.into()))
+        );
+
+        env.ledger().set_timestamp(3_000);
+        client.criar_epoca_recompensa(&3_000, &4_000, &100_0000000);
+        client.reivindicar_recompensa_livro(&leitor3, &65);
+        assert_eq!(client.obter_epoca_atual().unwrap().distribuido, 100_0000000);
+    }
+
+    #[test]
+    fn test_emblemas_de_conclusao() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        client.registrar_estrutura_livro(&64, &Testamento::Novo, &vec![&env, 15]);
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 25]);
+        let ara = registrar_ara(&env, &client);
+        abrir_epoca(&client);
+        registrar_hashes_capitulo(&env, &client, 64, 1, 15, &ara);
+        registrar_hashes_capitulo(&env, &client, 65, 1, 25, &ara);
+
+        client.marcar_capitulo_lido(&leitor, &65, &1, &ara, &None);
+        client.marcar_capitulo_lido(&leitor, &64, &1, &ara, &None);
+        assert!(client.emblema_do_livro(&leitor, &65).is_none());
+
+        env.ledger().set_timestamp(5_000);
+        client.reivindicar_recompensa_livro(&leitor, &65);
+        client.reivindicar_recompensa_livro(&leitor, &64);
+
+        assert_eq!(client.total_emblemas(), 2);
+        let judas = client.emblema_do_livro(&leitor, &65).unwrap();
+        assert_eq!(judas.serial, 1);
+        assert_eq!(judas.dono, leitor);
+        assert_eq!(judas.timestamp, 5_000);
+        assert_eq!(client.obter_emblema(&2).unwrap().livro_id, 64);
+
+        let emblemas = client.listar_emblemas(&leitor);
+        assert_eq!(emblemas.len(), 2);
+        assert_eq!(emblemas.get(1).unwrap().serial, 2);
+        assert!(client.obter_progresso(&leitor, &65).recompensa_recebida);
+
+        assert!(client.try_definir_uri_emblemas(&String::from_str(&env, "https://exemplo.org/emblemas")).is_err());
+        client.definir_uri_emblemas(&String::from_str(&env, "https://exemplo.org/emblemas/{id}.json"));
+        assert_eq!(client.uri_emblema(&2), String::from_str(&env, "https://exemplo.org/emblemas/2.json"));
+    }
+
+    #[test]
+    fn test_indicacoes() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let indicador = Address::generate(&env);
+        let novo1 = Address::generate(&env);
+        let novo2 = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        client.registrar_estrutura_livro(&64, &Testamento::Novo, &vec![&env, 15]);
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 25]);
+        let ara = registrar_ara(&env, &client);
+        abrir_epoca(&client);
+        registrar_hashes_capitulo(&env, &client, 64, 1, 15, &ara);
+        registrar_hashes_capitulo(&env, &client, 65, 1, 25, &ara);
+        client.configurar_indicacao(&10_0000000, &1);
+
+        // O indicador precisa ser um leitor
+        assert!(client.try_registrar_indicador(&novo1, &indicador).is_err());
+        client.marcar_lido(&indicador, &IdTexto { livro: 65, capitulo: 1, versiculo: 1 }, &ara, &None);
+
+        assert!(client.try_registrar_indicador(&novo1, &novo1).is_err());
+        client.registrar_indicador(&novo1, &indicador);
+        client.registrar_indicador(&novo2, &indicador);
+        assert!(client.try_registrar_indicador(&novo1, &indicador).is_err());
+
+        // Quem já leu não pode mais registrar indicador
+        assert!(client.try_registrar_indicador(&indicador, &novo1).is_err());
+
+        client.marcar_capitulo_lido(&novo1, &65, &1, &ara, &None);
+        client.marcar_capitulo_lido(&novo1, &64, &1, &ara, &None);
+        client.marcar_capitulo_lido(&novo2, &65, &1, &ara, &None);
+
+        client.reivindicar_recompensa_livro(&novo1, &65);
+        assert_eq!(client.obter_indicacoes(&indicador).bonus_pagos, 1);
+
+        // Só o primeiro livro do indicado gera bônus, e o limite do indicador é respeitado
+        client.reivindicar_recompensa_livro(&novo1, &64);
+        client.reivindicar_recompensa_livro(&novo2, &65);
+
+        let resumo = client.obter_indicacoes(&indicador);
+        assert_eq!(resumo.bonus_pagos, 1);
+        assert_eq!(resumo.indicados, vec![&env, novo1.clone(), novo2.clone()]);
+        assert_eq!(client.obter_indicacoes(&novo1).indicador, Some(indicador));
+    }
+
+    #[test]
+    fn test_perfis() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor1 = Address::generate(&env);
+        let leitor2 = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+
+        let apelido = String::from_str(&env, "josias_1");
+        let bio = String::from_str(&env, "Lendo a Bíblia em um ano");
+        let idioma = String::from_str(&env, "pt-BR");
+        let avatar: BytesN<32> = env.crypto().sha256(&Bytes::from_array(&env, &[1, 2, 3])).into();
+        client.definir_perfil(&leitor1, &apelido, &bio, &Some(avatar.clone()), &idioma, &Some(ara.clone()));
+
+        let perfil = client.obter_perfil(&leitor1).unwrap();
+        assert_eq!(perfil.apelido, apelido);
+        assert_eq!(perfil.hash_avatar, Some(avatar));
+        assert_eq!(client.obter_perfil_por_apelido(&apelido).unwrap().0, leitor1);
+
+        // Apelidos são únicos e restritos a letras minúsculas, dígitos e _
+        assert!(client.try_definir_perfil(&leitor2, &apelido, &bio, &None, &idioma, &None).is_err());
+        assert!(client.try_definir_perfil(&leitor2, &String::from_str(&env, "Maria"), &bio, &None, &idioma, &None).is_err());
+        assert!(client.try_definir_perfil(&leitor2, &String::from_str(&env, "maria"), &bio, &None, &idioma, &Some(symbol_short!("KJV"))).is_err());
+
+        // Trocar de apelido libera o anterior
+        let novo_apelido = String::from_str(&env, "josias");
+        client.definir_perfil(&leitor1, &novo_apelido, &bio, &None, &idioma, &None);
+        assert!(client.obter_perfil_por_apelido(&apelido).is_none());
+        client.definir_perfil(&leitor2, &apelido, &bio, &None, &idioma, &None);
+
+        client.marcar_lido(&leitor1, &id_texto, &ara, &None);
+        client.adicionar_reflexao(&leitor1, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+
+        let lista = client.listar_reflexoes_com_perfil(&id_texto, &OrdemReflexoes::Antigas, &10, &None).itens;
+        assert_eq!(lista.len(), 1);
+        assert_eq!(lista.get(0).unwrap().apelido, Some(novo_apelido));
+    }
+
+    #[test]
+    fn test_seguir_e_feed_do_autor() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let autor = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 3, &ara);
+
+        for versiculo in 1..=3 {
+            let id_texto = IdTexto { livro: 1, capitulo: 1, versiculo };
+            client.marcar_lido(&autor, &id_texto, &ara, &None);
+            let publica = versiculo != 2;
+            client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &publica, &vec![&env]);
+        }
+
+        assert!(client.try_seguir(&leitor, &leitor).is_err());
+        client.seguir(&leitor, &autor);
+        assert!(client.try_seguir(&leitor, &autor).is_err());
+        assert!(client.segue(&leitor, &autor));
+        assert_eq!(client.obter_contagem_seguidores(&autor).seguidores, 1);
+        assert_eq!(client.obter_contagem_seguidores(&leitor).seguindo, 1);
+        assert_eq!(client.listar_seguindo(&leitor, &10, &0), vec![&env, autor.clone()]);
+
+        // O feed mostra apenas as reflexões públicas, na ordem em que foram escritas
+        let feed = client.listar_reflexoes_do_autor(&autor, &false, &10, &0);
+        assert_eq!(feed.len(), 2);
+        assert_eq!(feed.get(0).unwrap().id_texto.versiculo, 1);
+        assert_eq!(feed.get(1).unwrap().id_texto.versiculo, 3);
+        assert_eq!(client.listar_reflexoes_do_autor(&autor, &false, &1, &2).len(), 1);
+
+        client.deixar_de_seguir(&leitor, &autor);
+        assert!(!client.segue(&leitor, &autor));
+        assert_eq!(client.obter_contagem_seguidores(&autor).seguidores, 0);
+        assert!(client.try_deixar_de_seguir(&leitor, &autor).is_err());
+    }
+
+    #[test]
+    fn test_listar_reflexoes_do_autor() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let autor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 3, &ara);
+
+        for versiculo in 1..=3 {
+            let id_texto = IdTexto { livro: 1, capitulo: 1, versiculo };
+            client.marcar_lido(&autor, &id_texto, &ara, &None);
+            let publica = versiculo == 1;
+            client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &publica, &vec![&env]);
+        }
+
+        assert_eq!(client.listar_reflexoes_do_autor(&autor, &false, &10, &0).len(), 1);
+
+        let diario = client.listar_reflexoes_do_autor(&autor, &true, &10, &0);
+        assert_eq!(diario.len(), 3);
+        assert_eq!(diario.get(2).unwrap().id_texto.versiculo, 3);
+        assert_eq!(client.listar_reflexoes_do_autor(&autor, &true, &2, &1).len(), 2);
+
+        // Sem a autenticação do autor, as privadas não podem ser listadas
+        env.mock_auths(&[]);
+        assert!(client.try_listar_reflexoes_do_autor(&autor, &true, &10, &0).is_err());
+        assert_eq!(client.listar_reflexoes_do_autor(&autor, &false, &10, &0).len(), 1);
+    }
+
+    #[test]
+    fn test_ordenar_reflexoes_publicas() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+
+        let autores = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        for autor in autores.iter() {
+            client.marcar_lido(autor, &id_texto, &ara, &None);
+            client.adicionar_reflexao(autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+        }
+
+        let antigas = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Antigas, &10, &None).itens;
+        assert_eq!(antigas.get(0).unwrap().leitor, autores[0]);
+
+        let recentes = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Recentes, &2, &None).itens;
+        assert_eq!(recentes.len(), 2);
+        assert_eq!(recentes.get(0).unwrap().leitor, autores[2]);
+        assert_eq!(recentes.get(1).unwrap().leitor, autores[1]);
+
+        // Duas curtidas para a terceira reflexão e uma para a segunda
+        let curtidor1 = Address::generate(&env);
+        let curtidor2 = Address::generate(&env);
+        client.curtir_reflexao(&curtidor1, &id_texto, &autores[2]);
+        client.curtir_reflexao(&curtidor2, &id_texto, &autores[2]);
+        client.curtir_reflexao(&curtidor1, &id_texto, &autores[1]);
+
+        let top = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::MaisCurtidas, &10, &None).itens;
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.get(0).unwrap().leitor, autores[2]);
+        assert_eq!(top.get(0).unwrap().curtidas, 2);
+        assert_eq!(top.get(1).unwrap().leitor, autores[1]);
+        assert_eq!(top.get(2).unwrap().leitor, autores[0]);
+
+        // Descurtir reposiciona o ranking; quem fica sem curtidas volta para depois dele
+        client.curtir_reflexao(&curtidor1, &id_texto, &autores[2]);
+        client.curtir_reflexao(&curtidor2, &id_texto, &autores[2]);
+        let top = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::MaisCurtidas, &10, &None).itens;
+        assert_eq!(top.len(), 3);
+        assert_eq!(top.get(0).unwrap().leitor, autores[1]);
+        assert_eq!(top.get(1).unwrap().leitor, autores[0]);
+        assert_eq!(top.get(2).unwrap().leitor, autores[2]);
+
+        // A paginação atravessa o fim do ranking sem repetir nem pular reflexões
+        let mut cursor = None;
+        let mut paginadas = vec![&env];
+        loop {
+            let pagina = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::MaisCurtidas, &1, &cursor);
+            for reflexao in pagina.itens.iter() {
+                paginadas.push_back(reflexao.leitor);
+            }
+            if !pagina.tem_mais {
+                break;
+            }
+            cursor = pagina.proximo_cursor;
+        }
+        assert_eq!(paginadas, vec![&env, autores[1].clone(), autores[0].clone(), autores[2].clone()]);
+    }
+
+    #[test]
+    fn test_paginacao_por_cursor() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+
+        for _ in 0..5 {
+            let autor = Address::generate(&env);
+            client.marcar_lido(&autor, &id_texto, &ara, &None);
+            client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+        }
+
+        let pagina1 = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Recentes, &2, &None);
+        assert_eq!(pagina1.itens.len(), 2);
+        assert!(pagina1.tem_mais);
+
+        let pagina2 = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Recentes, &2, &pagina1.proximo_cursor);
+        assert_eq!(pagina2.itens.len(), 2);
+        assert!(pagina2.tem_mais);
+        assert_ne!(pagina1.itens.get(1).unwrap().leitor, pagina2.itens.get(0).unwrap().leitor);
+
+        // Uma reflexão nova entre as chamadas não desloca a paginação
+        let autor_novo = Address::generate(&env);
+        client.marcar_lido(&autor_novo, &id_texto, &ara, &None);
+        client.adicionar_reflexao(&autor_novo, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+
+        let pagina3 = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Recentes, &2, &pagina2.proximo_cursor);
+        assert_eq!(pagina3.itens.len(), 1);
+        assert!(!pagina3.tem_mais);
+        assert_ne!(pagina3.itens.get(0).unwrap().leitor, pagina2.itens.get(1).unwrap().leitor);
+        assert_ne!(pagina3.itens.get(0).unwrap().leitor, autor_novo);
+
+        // Uma página exata não anuncia páginas vazias
+        let completa = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Antigas, &6, &None);
+        assert_eq!(completa.itens.len(), 6);
+        assert!(!completa.tem_mais);
+        assert_eq!(completa.proximo_cursor, None);
+
+        // Limite zero é tratado como 1, então a paginação sempre avança
+        let minima = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Antigas, &0, &None);
+        assert_eq!(minima.itens.len(), 1);
+        let seguinte = client.listar_reflexoes_publicas(&id_texto, &OrdemReflexoes::Antigas, &0, &minima.proximo_cursor);
+        assert_ne!(seguinte.itens.get(0).unwrap().leitor, minima.itens.get(0).unwrap().leitor);
+    }
+
+    #[test]
+    fn test_tags_de_reflexoes() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor1 = Address::generate(&env);
+        let leitor2 = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 2, &ara);
+
+        let fe = String::from_str(&env, "fé");
+        let perdao = String::from_str(&env, "perdão");
+        let oracao = String::from_str(&env, "oração");
+        client.registrar_tag(&fe);
+        client.registrar_tag(&perdao);
+        assert!(client.try_registrar_tag(&fe).is_err());
+        assert_eq!(client.listar_tags().len(), 2);
+
+        let gen_1_1 = id_gen_1_1();
+        let gen_1_2 = IdTexto { livro: 1, capitulo: 1, versiculo: 2 };
+        let conteudo = String::from_str(&env, "Reflexão");
+        client.marcar_lido(&leitor1, &gen_1_1, &ara, &None);
+        client.marcar_lido(&leitor2, &gen_1_2, &ara, &None);
+
+        // Apenas tags do vocabulário, sem repetições
+        assert!(client.try_adicionar_reflexao(&leitor1, &gen_1_1, &ara, &conteudo, &true, &vec![&env, oracao]).is_err());
+        assert!(client.try_adicionar_reflexao(&leitor1, &gen_1_1, &ara, &conteudo, &true, &vec![&env, fe.clone(), fe.clone()]).is_err());
+
+        client.adicionar_reflexao(&leitor1, &gen_1_1, &ara, &conteudo, &true, &vec![&env, fe.clone(), perdao.clone()]);
+        client.adicionar_reflexao(&leitor2, &gen_1_2, &ara, &conteudo, &true, &vec![&env, fe.clone()]);
+        assert_eq!(client.obter_reflexao(&leitor1, &gen_1_1).unwrap().tags, vec![&env, fe.clone(), perdao.clone()]);
+
+        let pagina = client.listar_reflexoes_por_tag(&fe, &10, &None);
+        assert_eq!(pagina.itens.len(), 2);
+        assert!(!pagina.tem_mais);
+        assert_eq!(pagina.itens.get(1).unwrap().leitor, leitor2);
+
+        let pagina = client.listar_reflexoes_por_tag(&perdao, &10, &None);
+        assert_eq!(pagina.itens.len(), 1);
+        assert_eq!(pagina.itens.get(0).unwrap().leitor, leitor1);
+    }
+
+    #[test]
+    fn test_denuncias_e_fila_de_moderacao() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let moderador = Address::generate(&env);
+        let autor = Address::generate(&env);
+        let comentarista = Address::generate(&env);
+        let denunciante1 = Address::generate(&env);
+        let denunciante2 = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+        client.adicionar_moderador(&moderador);
+        client.definir_limite_ocultacao(&2);
+
+        client.marcar_lido(&autor, &id_texto, &ara, &None);
+        client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+        client.comentar_reflexao(&comentarista, &id_texto, &autor, &String::from_str(&env, "Spam"));
+        client.comentar_reflexao(&comentarista, &id_texto, &autor, &String::from_str(&env, "Comentário"));
+
+        let reflexao = AlvoDenuncia::Reflexao(id_texto.clone(), autor.clone());
+        let comentario = AlvoDenuncia::Comentario(id_texto.clone(), autor.clone(), 0);
+
+        // Uma denúncia por usuário e item, e nunca do próprio conteúdo
+        client.denunciar(&denunciante1, &reflexao, &MotivoDenuncia::Ofensivo);
+        assert!(client.try_denunciar(&denunciante1, &reflexao, &MotivoDenuncia::Spam).is_err());
+        assert!(client.try_denunciar(&autor, &reflexao, &MotivoDenuncia::Spam).is_err());
+        assert!(client.try_denunciar(&denunciante1, &AlvoDenuncia::Comentario(id_texto.clone(), autor.clone(), 9), &MotivoDenuncia::Spam).is_err());
+        let denuncia_comentario = client.denunciar(&denunciante1, &comentario, &MotivoDenuncia::Spam);
+        assert_eq!(client.verificar_status_reflexao(&id_texto, &autor).status, StatusReflexao::Ativa);
+
+        // A segunda denúncia atinge o limite e coloca a reflexão em revisão
+        let denuncia_reflexao = client.denunciar(&denunciante2, &reflexao, &MotivoDenuncia::Ofensivo);
+        assert_eq!(client.verificar_status_reflexao(&id_texto, &autor).status, StatusReflexao::EmRevisao);
+        assert!(client.obter_reflexao(&autor, &id_texto).is_none());
+        assert!(client.try_curtir_reflexao(&denunciante1, &id_texto, &autor).is_err());
+        assert_eq!(client.listar_denuncias_abertas(&10, &0).len(), 3);
+
+        // Apenas moderadores resolvem; improcedente restaura a reflexão e fecha todas as denúncias dela
+        assert!(client.try_resolver_denuncia(&denunciante1, &denuncia_reflexao, &false).is_err());
+        client.resolver_denuncia(&moderador, &denuncia_reflexao, &false);
+        assert_eq!(client.verificar_status_reflexao(&id_texto, &autor).status, StatusReflexao::Restaurada);
+        assert!(!client.obter_denuncia(&0).unwrap().aberta);
+        assert!(client.try_resolver_denuncia(&moderador, &denuncia_reflexao, &true).is_err());
+
+        // Denúncias já resolvidas não contam para o limite de uma nova revisão
+        let denunciante3 = Address::generate(&env);
+        client.denunciar(&denunciante3, &reflexao, &MotivoDenuncia::Spam);
+        assert_eq!(client.verificar_status_reflexao(&id_texto, &autor).status, StatusReflexao::Restaurada);
+
+        // Procedente oculta o comentário denunciado
+        client.resolver_denuncia(&moderador, &denuncia_comentario, &true);
+        let comentarios = client.obter_comentarios(&id_texto, &autor, &None);
+        assert_eq!(comentarios.len(), 1);
+        assert_eq!(comentarios.get(0).unwrap().id, 1);
+        assert_eq!(client.obter_denuncia(&denuncia_comentario).unwrap().resolvida_por, Some(moderador.clone()));
+        assert_eq!(client.listar_denuncias_abertas(&10, &0).len(), 1);
+
+        // Um comentário apagado pelo autor após a denúncia ainda permite resolvê-la como procedente
+        let denuncia_apagado = client.denunciar(&denunciante2, &AlvoDenuncia::Comentario(id_texto.clone(), autor.clone(), 1), &MotivoDenuncia::Spam);
+        client.remover_comentario(&comentarista, &id_texto, &autor, &1);
+        client.resolver_denuncia(&moderador, &denuncia_apagado, &true);
+        assert!(client.obter_denuncia(&denuncia_apagado).unwrap().procedente);
+        assert_eq!(client.obter_comentarios(&id_texto, &autor, &Some(moderador.clone())).len(), 1);
+    }
+
+    #[test]
+    fn test_maquina_de_estados_da_reflexao() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let moderador = Address::generate(&env);
+        let autor = Address::generate(&env);
+        let outro = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+        let motivo = String::from_str(&env, "Motivo");
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+        client.adicionar_moderador(&moderador);
+
+        client.marcar_lido(&autor, &id_texto, &ara, &None);
+        client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+        assert_eq!(client.verificar_status_reflexao(&id_texto, &autor).ator, autor);
+
+        // O autor oculta e reexibe a própria reflexão; outros usuários não podem
+        assert!(client.try_alterar_status_reflexao(&outro, &id_texto, &autor, &StatusReflexao::Oculta, &motivo).is_err());
+        client.alterar_status_reflexao(&autor, &id_texto, &autor, &StatusReflexao::Oculta, &motivo);
+        assert!(client.obter_reflexao(&autor, &id_texto).is_none());
+        assert_eq!(client.listar_reflexoes_do_autor(&autor, &true, &10, &0).len(), 1);
+        assert!(client.try_curtir_reflexao(&outro, &id_texto, &autor).is_err());
+        client.alterar_status_reflexao(&autor, &id_texto, &autor, &StatusReflexao::Ativa, &motivo);
+
+        // O autor não restaura o que o moderador removeu; o moderador sim
+        client.alterar_status_reflexao(&moderador, &id_texto, &autor, &StatusReflexao::RemovidaPorModerador, &motivo);
+        assert!(client.try_alterar_status_reflexao(&autor, &id_texto, &autor, &StatusReflexao::Ativa, &motivo).is_err());
+        assert!(client.try_alterar_status_reflexao(&autor, &id_texto, &autor, &StatusReflexao::Restaurada, &motivo).is_err());
+        client.alterar_status_reflexao(&moderador, &id_texto, &autor, &StatusReflexao::Restaurada, &motivo);
+
+        let registro = client.verificar_status_reflexao(&id_texto, &autor);
+        assert_eq!(registro.status, StatusReflexao::Restaurada);
+        assert_eq!(registro.ator, moderador);
+        assert_eq!(registro.motivo, motivo);
+        assert!(client.obter_reflexao(&autor, &id_texto).is_some());
+
+        // A remoção pelo autor é definitiva
+        client.alterar_status_reflexao(&autor, &id_texto, &autor, &StatusReflexao::Removida, &motivo);
+        assert!(client.try_alterar_status_reflexao(&moderador, &id_texto, &autor, &StatusReflexao::Restaurada, &motivo).is_err());
+        assert_eq!(client.historico_status_reflexao(&id_texto, &autor).len(), 6);
+    }
+
+    #[test]
+    fn test_ocultar_comentarios() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let moderador = Address::generate(&env);
+        let autor = Address::generate(&env);
+        let comentarista = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+        client.adicionar_moderador(&moderador);
+
+        client.marcar_lido(&autor, &id_texto, &ara, &None);
+        client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+        for _ in 0..3 {
+            client.comentar_reflexao(&comentarista, &id_texto, &autor, &String::from_str(&env, "Comentário"));
+        }
+
+        // Só o autor da reflexão e moderadores ocultam comentários
+        assert!(client.try_ocultar_comentario(&comentarista, &id_texto, &autor, &0).is_err());
+        client.ocultar_comentario(&autor, &id_texto, &autor, &0);
+        client.ocultar_comentario(&moderador, &id_texto, &autor, &1);
+
+        assert_eq!(client.obter_comentarios(&id_texto, &autor, &None).len(), 1);
+        assert_eq!(client.obter_comentarios(&id_texto, &autor, &Some(comentarista.clone())).len(), 1);
+
+        let todos = client.obter_comentarios(&id_texto, &autor, &Some(autor.clone()));
+        assert_eq!(todos.len(), 3);
+        assert_eq!(todos.get(0).unwrap().ocultacao, OcultacaoComentario::PeloAutorDaReflexao);
+        assert_eq!(todos.get(1).unwrap().ocultacao, OcultacaoComentario::PorModerador);
+        assert_eq!(todos.get(1).unwrap().ocultado_por, Some(moderador.clone()));
+        assert_eq!(client.obter_comentarios(&id_texto, &autor, &Some(moderador.clone())).len(), 3);
+
+        // O autor não reexibe o que o moderador ocultou, nem sobrescreve a ocultação para depois reexibir
+        assert!(client.try_reexibir_comentario(&autor, &id_texto, &autor, &1).is_err());
+        assert!(client.try_ocultar_comentario(&autor, &id_texto, &autor, &1).is_err());
+        assert!(client.try_reexibir_comentario(&autor, &id_texto, &autor, &1).is_err());
+        assert_eq!(client.obter_comentarios(&id_texto, &autor, &None).len(), 1);
+        assert_eq!(
+            client.obter_comentarios(&id_texto, &autor, &Some(moderador.clone())).get(1).unwrap().ocultacao,
+            OcultacaoComentario::PorModerador
+        );
+        client.reexibir_comentario(&autor, &id_texto, &autor, &0);
+        client.reexibir_comentario(&moderador, &id_texto, &autor, &1);
+        assert_eq!(client.obter_comentarios(&id_texto, &autor, &None).len(), 3);
+    }
+
+    #[test]
+    fn test_bloqueio_de_interacoes() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let autor = Address::generate(&env);
+        let incomodo = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+        let comentario = String::from_str(&env, "Comentário");
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+
+        client.marcar_lido(&autor, &id_texto, &ara, &None);
+        client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+
+        assert!(client.try_bloquear(&autor, &autor).is_err());
+        client.bloquear(&autor, &incomodo);
+        assert!(client.try_bloquear(&autor, &incomodo).is_err());
+        assert_eq!(client.listar_bloqueados(&autor), vec![&env, incomodo.clone()]);
+
+        assert_eq!(
+            client.try_comentar_reflexao(&incomodo, &id_texto, &autor, &comentario),
+            Err(Ok(Erro::UsuarioBloqueado.into()))
+        );
+        assert_eq!(
+            client.try_curtir_reflexao(&incomodo, &id_texto, &autor),
+            Err(Ok(Erro::UsuarioBloqueado.into()))
+        );
+
+        client.desbloquear(&autor, &incomodo);
+        assert_eq!(client.listar_bloqueados(&autor).len(), 0);
+        client.comentar_reflexao(&incomodo, &id_texto, &autor, &comentario);
+        client.curtir_reflexao(&incomodo, &id_texto, &autor);
+        assert!(client.try_desbloquear(&autor, &incomodo).is_err());
+
+        // Bloqueado depois de curtir, ainda pode retirar a curtida, mas não curtir de novo
+        client.bloquear(&autor, &incomodo);
+        client.curtir_reflexao(&incomodo, &id_texto, &autor);
+        assert_eq!(client.obter_reflexao(&autor, &id_texto).unwrap().curtidas, 0);
+        assert_eq!(
+            client.try_curtir_reflexao(&incomodo, &id_texto, &autor),
+            Err(Ok(Erro::UsuarioBloqueado.into()))
+        );
+    }
+
+    #[test]
+    fn test_recompensas_verificam_cobertura_da_estrutura() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        let ara = registrar_ara(&env, &client);
+        abrir_epoca(&client);
+
+        assert_eq!(
+            client.try_reivindicar_recompensa_livro(&leitor, &65),
+            Err(Ok(Erro::LivroNaoRegistrado.into()))
+        );
+
+        // Judas registrado com 30 versículos; o leitor lê do 6 ao 30
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 30]);
+        registrar_hashes_capitulo(&env, &client, 65, 1, 30, &ara);
+        client.marcar_intervalo_lido(
+            &leitor,
+            &IdTexto { livro: 65, capitulo: 1, versiculo: 6 },
+            &IdTexto { livro: 65, capitulo: 1, versiculo: 30 },
+            &ara,
+            &None,
+        );
+
+        // A estrutura corrigida tem 25 versículos: os contadores batem, mas estão desatualizados
+        client.registrar_estrutura_livro(&65, &Testamento::Novo, &vec![&env, 25]);
+        client.definir_livros_canon(&Testamento::Novo, &1);
+        assert_eq!(client.obter_progresso_testamento(&leitor, &Testamento::Novo), 25);
+        assert_eq!(client.obter_progresso(&leitor, &65).meta, Some(25));
+        assert!(client.try_reivindicar_recompensa_livro(&leitor, &65).is_err());
+        assert!(client.try_reivindicar_testamento(&leitor, &Testamento::Novo).is_err());
+        assert!(client.try_reivindicar_biblia(&leitor).is_err());
+
+        for versiculo in 1..=5 {
+            client.marcar_lido(&leitor, &IdTexto { livro: 65, capitulo: 1, versiculo }, &ara, &None);
+        }
+        assert!(client.try_reivindicar_recompensa_livro(&leitor, &65).is_err());
+        assert_eq!(client.recontar_progresso(&leitor, &65), 25);
+        client.reivindicar_recompensa_livro(&leitor, &65);
+
+        // Quem começa a ler depois da mudança já conta pela estrutura atual
+        let novo_leitor = Address::generate(&env);
+        client.marcar_capitulo_lido(&novo_leitor, &65, &1, &ara, &None);
+        client.reivindicar_recompensa_livro(&novo_leitor, &65);
+        client.reivindicar_testamento(&leitor, &Testamento::Novo);
+    }
+
+    #[test]
+    fn test_recompensas_exigem_canon_completo() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let leitor = Address::generate(&env);
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        abrir_epoca(&client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 31, &ara);
+        registrar_hashes_capitulo(&env, &client, 1, 2, 25, &ara);
+        client.marcar_capitulo_lido(&leitor, &1, &1, &ara, &None);
+        client.marcar_capitulo_lido(&leitor, &1, &2, &ara, &None);
+
+        // Só Gênesis registrado: ler o livro inteiro não conclui o testamento nem a Bíblia
+        assert!(!client.canon_completo(&Testamento::Antigo));
+        assert!(client.try_reivindicar_testamento(&leitor, &Testamento::Antigo).is_err());
+        assert!(client.try_reivindicar_biblia(&leitor).is_err());
+
+        client.definir_livros_canon(&Testamento::Antigo, &39);
+        client.definir_livros_canon(&Testamento::Novo, &27);
+        assert!(!client.canon_completo(&Testamento::Antigo));
+        assert!(client.try_reivindicar_testamento(&leitor, &Testamento::Antigo).is_err());
+        assert!(client.try_reivindicar_biblia(&leitor).is_err());
+        client.reivindicar_recompensa_livro(&leitor, &1);
+
+        // Um cânon de um único livro torna o testamento completo, mas a Bíblia continua incompleta
+        client.definir_livros_canon(&Testamento::Antigo, &1);
+        assert!(client.canon_completo(&Testamento::Antigo));
+        client.reivindicar_testamento(&leitor, &Testamento::Antigo);
+        assert!(client.try_reivindicar_biblia(&leitor).is_err());
+    }
+
+    #[test]
+    fn test_denuncia_procedente_nao_altera_remocao_do_autor() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let moderador = Address::generate(&env);
+        let autor = Address::generate(&env);
+        let denunciante = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+        let motivo = String::from_str(&env, "Motivo");
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+        client.adicionar_moderador(&moderador);
+
+        client.marcar_lido(&autor, &id_texto, &ara, &None);
+        client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+        let denuncia_id = client.denunciar(&denunciante, &AlvoDenuncia::Reflexao(id_texto.clone(), autor.clone()), &MotivoDenuncia::Spam);
+
+        client.alterar_status_reflexao(&autor, &id_texto, &autor, &StatusReflexao::Removida, &motivo);
+        client.resolver_denuncia(&moderador, &denuncia_id, &true);
+
+        assert!(!client.obter_denuncia(&denuncia_id).unwrap().aberta);
+        assert_eq!(client.verificar_status_reflexao(&id_texto, &autor).status, StatusReflexao::Removida);
+        assert!(client.try_alterar_status_reflexao(&moderador, &id_texto, &autor, &StatusReflexao::Restaurada, &motivo).is_err());
+        assert!(client.obter_reflexao(&autor, &id_texto).is_none());
+    }
+
+    #[test]
+    fn test_restaurar_reflexao_oculta_mantem_ocultacao() {
+        let env = Env::default();
+        let contract_id = env.register(ContratoBiblia, ());
+        let client = ContratoBibliaClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let moderador = Address::generate(&env);
+        let autor = Address::generate(&env);
+        let denunciante = Address::generate(&env);
+        let id_texto = id_gen_1_1();
+        let motivo = String::from_str(&env, "Motivo");
+
+        env.mock_all_auths();
+        client.initialize(&admin);
+        registrar_genesis(&env, &client);
+        let ara = registrar_ara(&env, &client);
+        registrar_hashes_capitulo(&env, &client, 1, 1, 1, &ara);
+        client.adicionar_moderador(&moderador);
+
+        client.marcar_lido(&autor, &id_texto, &ara, &None);
+        client.adicionar_reflexao(&autor, &id_texto, &ara, &String::from_str(&env, "Reflexão"), &true, &vec![&env]);
+        let denuncia_id = client.denunciar(&denunciante, &AlvoDenuncia::Reflexao(id_texto.clone(), autor.clone()), &MotivoDenuncia::Spam);
+        client.alterar_status_reflexao(&autor, &id_texto, &autor, &StatusReflexao::Oculta, &motivo);
+
+        // Denúncia improcedente sobre uma reflexão oculta devolve a ocultação do autor
+        client.alterar_status_reflexao(&moderador, &id_texto, &autor, &StatusReflexao::EmRevisao, &motivo);
+        client.resolver_denuncia(&moderador, &denuncia_id, &false);
+        assert_eq!(client.verificar_status_reflexao(&id_texto, &autor).status, StatusReflexao::Oculta);
+        assert!(client.obter_reflexao(&autor, &id_texto).is_none());
+
+        // O mesmo vale para a restauração direta após uma remoção pelo moderador
+        client.alterar_status_reflexao(&moderador, &id_texto, &autor, &StatusReflexao::RemovidaPorModerador, &motivo);
+        client.alterar_status_reflexao(&moderador, &id_texto, &autor, &StatusReflexao::Restaurada, &motivo);
+        assert_eq!(client.verificar_status_reflexao(&id_texto, &autor).status, StatusReflexao::Oculta);
+        assert!(client.obter_reflexao(&autor, &id_texto).is_none());
+
+        // Só o autor decide reexibir
+        client.alterar_status_reflexao(&autor, &id_texto, &autor, &StatusReflexao::Ativa, &motivo);
+        assert!(client.obter_reflexao(&autor, &id_texto).is_some());
+    }
+}
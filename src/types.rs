@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, String, BytesN};
+use soroban_sdk::{contracterror, contracttype, Address, String, BytesN, Symbol, Vec};
 
 // Estrutura principal para armazenar reflexões dos usuários
 /// Combina o conteúdo com metadados para verificação e controle
@@ -7,31 +7,176 @@ use soroban_sdk::{contracttype, Address, String, BytesN};
 pub struct Reflexao {
     pub leitor: Address,
     pub id_texto: IdTexto,
+    pub traducao: Symbol,
     pub conteudo: String,
     pub timestamp: u64,
     pub hash_reflexao: BytesN<32>,
     pub publica: bool,
     pub curtidas: u32,
+    pub tags: Vec<String>,
 }
 
 /// Estrutura para comentários em reflexões públicas
 /// Permite discussões e interações entre usuários
+/// O `id` é estável dentro da reflexão, mesmo após remoções
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Comentario {
+    pub id: u32,
     pub autor: Address,
     pub conteudo: String,
     pub timestamp: u64,
     pub curtidas: u32,
+    pub ocultacao: OcultacaoComentario,
+    pub ocultado_por: Option<Address>,
+}
+
+/// Visibilidade de um comentário e, se oculto, por qual papel
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OcultacaoComentario {
+    Visivel,
+    PeloAutorDaReflexao,
+    PorModerador,
+}
+
+/// Perfil público de um usuário, exibido junto às suas reflexões e comentários
+/// `hash_avatar` é o hash do conteúdo da imagem armazenada fora da blockchain
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Perfil {
+    pub apelido: String,
+    pub bio: String,
+    pub hash_avatar: Option<BytesN<32>>,
+    pub idioma: String,
+    pub traducao_preferida: Option<Symbol>,
+    pub atualizado_em: u64,
+}
+
+/// Reflexão pública acompanhada do apelido e avatar do autor, quando ele tiver perfil
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReflexaoComPerfil {
+    pub reflexao: Reflexao,
+    pub apelido: Option<String>,
+    pub hash_avatar: Option<BytesN<32>>,
+}
+
+/// Página de reflexões com o cursor para buscar a seguinte
+/// `tem_mais` indica se vale a pena pedir outra página a partir de `proximo_cursor`,
+/// que é `None` quando não há mais nada a percorrer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaginaReflexoes {
+    pub itens: Vec<Reflexao>,
+    pub proximo_cursor: Option<u32>,
+    pub tem_mais: bool,
+}
+
+/// Página de `listar_reflexoes_com_perfil`, com o mesmo cursor de `PaginaReflexoes`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaginaReflexoesComPerfil {
+    pub itens: Vec<ReflexaoComPerfil>,
+    pub proximo_cursor: Option<u32>,
+    pub tem_mais: bool,
+}
+
+/// Quantidade de seguidores de um leitor e de leitores que ele segue
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContagemSeguidores {
+    pub seguidores: u32,
+    pub seguindo: u32,
+}
+
+/// Ordem de listagem das reflexões públicas de um texto
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OrdemReflexoes {
+    Recentes,
+    Antigas,
+    MaisCurtidas,
 }
 
 /// Enum para controle de status das reflexões
 /// Permite moderação e controle de visibilidade
+/// Apenas `Ativa` e `Restaurada` são visíveis; `Removida` (pelo autor) é definitiva
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum StatusReflexao {
     Ativa,
     Removida,
+    EmRevisao,
+    Oculta,
+    RemovidaPorModerador,
+    Restaurada,
+}
+
+/// Transição de status de uma reflexão: novo status, quem o definiu, quando e por quê
+/// Transições automáticas (limite de denúncias) têm o próprio contrato como `ator`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistroStatusReflexao {
+    pub status: StatusReflexao,
+    pub ator: Address,
+    pub timestamp: u64,
+    pub motivo: String,
+}
+
+/// Motivo informado em uma denúncia
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MotivoDenuncia {
+    Spam,
+    Ofensivo,
+    Assedio,
+    ConteudoImproprio,
+    Outro,
+}
+
+/// Item denunciado: a reflexão (texto, autor) ou um comentário dela (texto, autor, id do comentário)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AlvoDenuncia {
+    Reflexao(IdTexto, Address),
+    Comentario(IdTexto, Address, u32),
+}
+
+/// Denúncia de um usuário e, depois de resolvida, a decisão do moderador
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Denuncia {
+    pub id: u32,
+    pub denunciante: Address,
+    pub alvo: AlvoDenuncia,
+    pub motivo: MotivoDenuncia,
+    pub timestamp: u64,
+    pub aberta: bool,
+    pub procedente: bool,
+    pub resolvida_por: Option<Address>,
+}
+
+/// Metadados de uma tradução/versão da Bíblia (ex: ARA, NVI, ACF, KJV)
+/// O código é usado junto com o `IdTexto` como chave dos hashes registrados
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Traducao {
+    pub codigo: Symbol,
+    pub nome: String,
+    pub idioma: String,
+    pub licenca: String,
+}
+
+/// Registro de uma correção no hash oficial de um texto
+/// Mantém o hash substituído e o motivo informado pelo admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevisaoHash {
+    pub hash_anterior: BytesN<32>,
+    pub hash_novo: BytesN<32>,
+    pub motivo: String,
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -42,6 +187,199 @@ pub struct IdTexto {
     pub versiculo: u32,
 }
 
+/// Testamento ao qual um livro pertence
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Testamento {
+    Antigo,
+    Novo,
+}
+
+/// Estrutura canônica de um livro registrada pelo admin
+/// `versiculos_por_capitulo[i]` guarda o total de versículos do capítulo `i + 1`
+/// `revisao` aumenta sempre que um novo registro remove versículos do livro
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EstruturaLivro {
+    pub testamento: Testamento,
+    pub versiculos_por_capitulo: Vec<u32>,
+    pub total_versiculos: u32,
+    pub revisao: u32,
+}
+
+/// Registro de uma leitura: tradução usada e momento em que foi feita
+/// `sequencia` é o número do ledger em que a leitura foi registrada
+/// Cada registro fica na chave própria `Leitura(leitor, id_texto)`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistroLeitura {
+    pub id_texto: IdTexto,
+    pub traducao: Symbol,
+    pub timestamp: u64,
+    pub sequencia: u32,
+}
+
+/// Sequência de dias consecutivos de leitura de um leitor
+/// Os dias são derivados de `env.ledger().timestamp()` (dias desde a época Unix)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SequenciaDias {
+    pub atual: u32,
+    pub maior: u32,
+    pub ultimo_dia: u64,
+}
+
+/// Trecho contínuo de leitura dentro de um mesmo livro (inclusive nas duas pontas)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntervaloLeitura {
+    pub inicio: IdTexto,
+    pub fim: IdTexto,
+}
+
+/// Plano de leitura publicado por um admin ou curador (ex: Bíblia em um ano)
+/// As tarefas de cada dia ficam em `DataKey::DiaPlano(id, dia)`; o plano aceita
+/// inscrições quando `dias_definidos` alcança `duracao_dias`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanoLeitura {
+    pub id: u32,
+    pub nome: String,
+    pub criador: Address,
+    pub duracao_dias: u32,
+    pub dias_definidos: u32,
+}
+
+/// Inscrição de um leitor em um plano, com a adesão acumulada
+/// `dia_inicio` é o índice do dia (timestamp / 86400) em que o plano começa para o leitor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InscricaoPlano {
+    pub plano_id: u32,
+    pub dia_inicio: u64,
+    pub dias_concluidos: u32,
+    pub dias_no_prazo: u32,
+    pub recompensa_recebida: bool,
+}
+
+/// Tarefa de um dia do plano para o leitor
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TarefaDia {
+    pub dia: u32,
+    pub intervalos: Vec<IntervaloLeitura>,
+    pub concluida: bool,
+}
+
+/// Resumo da adesão de um leitor a um plano
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgressoPlano {
+    pub plano_id: u32,
+    pub duracao_dias: u32,
+    pub dias_decorridos: u32,
+    pub dias_concluidos: u32,
+    pub dias_no_prazo: u32,
+    pub recompensa_recebida: bool,
+}
+
+/// Resultado de `marcar_lido`
+/// `meta_livro` é None enquanto o admin não registrar a estrutura do livro
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResultadoLeitura {
+    pub nova_leitura: bool,
+    pub progresso_livro: u32,
+    pub meta_livro: Option<u32>,
+}
+
+/// Progresso de um leitor em um livro
+/// `percentual_bps` é o progresso em pontos-base (10000 = 100%), limitado a 10000
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgressoLivro {
+    pub livro_id: u32,
+    pub lidos: u32,
+    pub meta: Option<u32>,
+    pub percentual_bps: u32,
+    pub recompensa_recebida: bool,
+}
+
+/// Época de recompensas configurada pelo admin
+/// Reivindicações só são aceitas entre `inicio` e `fim` (timestamps) e até esgotar `orcamento`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EpocaRecompensa {
+    pub id: u32,
+    pub inicio: u64,
+    pub fim: u64,
+    pub orcamento: u128,
+    pub distribuido: u128,
+}
+
+/// Emblema intransferível (soulbound) cunhado ao concluir um livro
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Emblema {
+    pub serial: u32,
+    pub dono: Address,
+    pub livro_id: u32,
+    pub timestamp: u64,
+}
+
+/// Configuração do bônus de indicação definida pelo admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigIndicacao {
+    pub bonus: u128,
+    pub max_bonus_por_indicador: u32,
+}
+
+/// Relação de indicações de um usuário, vista pelos dois lados
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResumoIndicacoes {
+    pub indicador: Option<Address>,
+    pub indicados: Vec<Address>,
+    pub bonus_pagos: u32,
+}
+
+/// Erros tipados retornados pelo contrato
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Erro {
+    LivroNaoRegistrado = 1,
+    CapituloInvalido = 2,
+    VersiculoInvalido = 3,
+    IntervaloInvalido = 4,
+    TextoNaoRegistrado = 5,
+    RecompensasPausadas = 6,
+    SemEpocaAtiva = 7,
+    ForaDaEpoca = 8,
+    OrcamentoEsgotado = 9,
+    UsuarioBloqueado = 10,
+}
+
 // Constantes para validação e limites do sistema
 pub const MAX_REFLEXAO_CHARS: u32 = 500;
-pub const MAX_COMENTARIO_CHARS: u32 = 200;
\ No newline at end of file
+pub const MAX_COMENTARIO_CHARS: u32 = 200;
+pub const MAX_VERSICULOS_LOTE: u32 = 200;
+pub const MAX_LIMITE_PAGINA: u32 = 50;
+pub const SEGUNDOS_POR_DIA: u64 = 86_400;
+pub const MARCOS_SEQUENCIA: [u32; 5] = [7, 30, 100, 365, 1000];
+pub const MAX_NOME_PLANO_CHARS: u32 = 100;
+pub const MAX_URI_CHARS: u32 = 200;
+pub const MAX_INDICADOS: u32 = 100;
+pub const MIN_APELIDO_CHARS: u32 = 3;
+pub const MAX_APELIDO_CHARS: u32 = 32;
+pub const MAX_BIO_CHARS: u32 = 280;
+pub const MAX_IDIOMA_CHARS: u32 = 16;
+pub const MAX_SEGUINDO: u32 = 500;
+pub const MAX_TOP_REFLEXOES: u32 = 20;
+pub const MAX_VARREDURA_PAGINA: u32 = 200;
+pub const MAX_TAG_CHARS: u32 = 24;
+pub const MAX_TAGS_REFLEXAO: u32 = 3;
+pub const MAX_TAGS_VOCABULARIO: u32 = 100;
+pub const MAX_MOTIVO_CHARS: u32 = 200;
+pub const MAX_BLOQUEADOS: u32 = 500;